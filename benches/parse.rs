@@ -0,0 +1,41 @@
+use cookie_parser::{
+    parse_cookie_string, parse_cookie_string_borrowed, parse_cookie_string_fast, parse_set_cookie,
+    parse_set_cookie_fast,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const COOKIE_STRING: &str = "Session=123123123; AnalyticsID=0b3af9e8-7a52-44c2-9b1a-0ed4a2a56fc8; Username=shabashab; theme=dark; csrftoken=\"a3fWa0b3af9e8==\"";
+
+const SET_COOKIE_STRING: &str = "id=a3fWa; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Max-Age=2592000; Domain=.example.com; Path=/docs; Secure; HttpOnly; SameSite=Lax; Priority=High";
+
+fn bench_cookie_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cookie_string");
+
+    group.bench_function("grammar", |b| {
+        b.iter(|| parse_cookie_string(black_box(COOKIE_STRING)))
+    });
+    group.bench_function("grammar_borrowed", |b| {
+        b.iter(|| parse_cookie_string_borrowed(black_box(COOKIE_STRING)))
+    });
+    group.bench_function("fast", |b| {
+        b.iter(|| parse_cookie_string_fast(black_box(COOKIE_STRING)))
+    });
+
+    group.finish();
+}
+
+fn bench_set_cookie(c: &mut Criterion) {
+    let mut group = c.benchmark_group("set_cookie");
+
+    group.bench_function("grammar", |b| {
+        b.iter(|| parse_set_cookie(black_box(SET_COOKIE_STRING)))
+    });
+    group.bench_function("fast", |b| {
+        b.iter(|| parse_set_cookie_fast(black_box(SET_COOKIE_STRING)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_cookie_string, bench_set_cookie);
+criterion_main!(benches);
@@ -0,0 +1,85 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{CookieDomain, CookiePair, Priority, SameSite, SetCookie};
+
+/// A borrowed counterpart of [`CookiePair`] whose name and value point into the parsed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CookiePairRef<'a> {
+    pub name: &'a str,
+    /// The value without the surrounding DQUOTEs, if it was quoted.
+    pub value: &'a str,
+    /// Whether the value was wrapped in DQUOTEs.
+    pub quoted: bool,
+}
+
+impl CookiePairRef<'_> {
+    /// Copy the name and value into an owned [`CookiePair`].
+    pub fn into_owned(self) -> CookiePair {
+        CookiePair {
+            name: String::from(self.name),
+            value: String::from(self.value),
+            quoted: self.quoted,
+        }
+    }
+}
+
+/// A borrowed counterpart of [`SetCookie`].
+///
+/// The cookie pair, `Path` and extensions point into the parsed input; the typed attributes are
+/// the same as in [`SetCookie`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetCookieRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub pair: CookiePairRef<'a>,
+    pub secure: bool,
+    pub http_only: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::max_age"))]
+    pub max_age: Option<Duration>,
+    pub domain: Option<CookieDomain>,
+    pub expires: Option<DateTime<Utc>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub path: Option<&'a str>,
+    pub same_site: Option<SameSite>,
+    pub partitioned: bool,
+    pub priority: Option<Priority>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub extensions: Vec<&'a str>,
+}
+
+impl<'a> SetCookieRef<'a> {
+    // A set-cookie structure without any attributes.
+    pub(crate) fn from_pair(pair: CookiePairRef<'a>) -> SetCookieRef<'a> {
+        SetCookieRef {
+            pair,
+            http_only: false,
+            secure: false,
+            domain: None,
+            max_age: None,
+            path: None,
+            expires: None,
+            same_site: None,
+            partitioned: false,
+            priority: None,
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Copy the borrowed strings into an owned [`SetCookie`].
+    pub fn into_owned(self) -> SetCookie {
+        SetCookie {
+            pair: self.pair.into_owned(),
+            secure: self.secure,
+            http_only: self.http_only,
+            max_age: self.max_age,
+            domain: self.domain,
+            expires: self.expires,
+            path: self.path.map(String::from),
+            same_site: self.same_site,
+            partitioned: self.partitioned,
+            priority: self.priority,
+            extensions: self.extensions.into_iter().map(String::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use pest::Parser;
+use thiserror::Error;
+
+use crate::{
+    parse_cookie_date, CookieDomain, CookiePair, Grammar, Priority, Rule, SameSite, SetCookie,
+};
+
+/// An error that can occur when building a set-cookie structure.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CookieBuildError {
+    #[error("Invalid cookie name '{0}'")]
+    ErrorInvalidName(String),
+    #[error("Invalid cookie value '{0}'")]
+    ErrorInvalidValue(String),
+    #[error("Invalid value '{value}' for attribute '{name}'")]
+    ErrorInvalidAttributeValue { name: String, value: String },
+}
+
+/// A builder for [`SetCookie`], created by [`SetCookie::builder`].
+///
+/// Every part is checked against the rules in grammar.pest when [`build`](Self::build) is called,
+/// so a built set-cookie structure always serializes to a header that `parse_set_cookie` accepts.
+#[derive(Debug, Clone)]
+pub struct SetCookieBuilder {
+    set_cookie: SetCookie,
+    domain: Option<String>,
+}
+
+impl SetCookie {
+    /// Start building a set-cookie structure for the given cookie name and unquoted value.
+    pub fn builder(name: &str, value: &str) -> SetCookieBuilder {
+        SetCookieBuilder {
+            set_cookie: SetCookie::from_pair(CookiePair {
+                name: String::from(name),
+                value: String::from(value),
+                quoted: false,
+            }),
+            domain: None,
+        }
+    }
+}
+
+impl SetCookieBuilder {
+    /// Wrap the value in DQUOTEs.
+    pub fn quoted(mut self, quoted: bool) -> Self {
+        self.set_cookie.pair.quoted = quoted;
+        self
+    }
+
+    /// Set the `Expires` attribute. The header carries whole seconds, so the fraction of a second
+    /// is dropped when building.
+    pub fn expires(mut self, expires: DateTime<Utc>) -> Self {
+        self.set_cookie.expires = Some(expires);
+        self
+    }
+
+    /// Set the `Max-Age` attribute. The header carries whole seconds, so the fraction of a second
+    /// is dropped when building.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.set_cookie.max_age = Some(max_age);
+        self
+    }
+
+    /// Set the `Domain` attribute. The value is interpreted like a parsed `Domain` attribute.
+    pub fn domain(mut self, domain: &str) -> Self {
+        self.domain = Some(String::from(domain));
+        self
+    }
+
+    pub fn path(mut self, path: &str) -> Self {
+        self.set_cookie.path = Some(String::from(path));
+        self
+    }
+
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.set_cookie.secure = secure;
+        self
+    }
+
+    pub fn http_only(mut self, http_only: bool) -> Self {
+        self.set_cookie.http_only = http_only;
+        self
+    }
+
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.set_cookie.same_site = Some(same_site);
+        self
+    }
+
+    pub fn partitioned(mut self, partitioned: bool) -> Self {
+        self.set_cookie.partitioned = partitioned;
+        self
+    }
+
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.set_cookie.priority = Some(priority);
+        self
+    }
+
+    /// Add an extension attribute such as `Foo=bar`. Known attributes are rejected; use their
+    /// own methods instead.
+    pub fn extension(mut self, extension: &str) -> Self {
+        self.set_cookie.extensions.push(String::from(extension));
+        self
+    }
+
+    /// Validate every part and return the set-cookie structure, or the first problem found.
+    pub fn build(self) -> Result<SetCookie, CookieBuildError> {
+        let mut set_cookie = self.set_cookie;
+        let pair = &set_cookie.pair;
+
+        if !matches_rule(Rule::single_cookie_name, &pair.name) {
+            return Err(CookieBuildError::ErrorInvalidName(pair.name.clone()));
+        }
+
+        // An empty value is allowed, as in removal headers.
+        let raw_value = pair.raw_value();
+
+        if !raw_value.is_empty() && !matches_rule(Rule::single_cookie_value, &raw_value) {
+            return Err(CookieBuildError::ErrorInvalidValue(pair.value.clone()));
+        }
+
+        // Drop fractions of a second, which the header cannot carry, so that the result survives
+        // a round trip.
+        set_cookie.expires = set_cookie.expires.map(|expires| expires.trunc_subsecs(0));
+        set_cookie.max_age = set_cookie
+            .max_age
+            .map(|max_age| Duration::seconds(max_age.num_seconds()));
+
+        if let Some(expires) = set_cookie.expires {
+            // Only dates that can be written as a cookie-date survive a round trip.
+            let value = expires.format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+
+            if parse_cookie_date(&value).is_none() {
+                return Err(invalid_attribute_value("Expires", &value));
+            }
+        }
+
+        if let Some(domain) = self.domain {
+            let parsed_domain = attribute_value(&format!("Domain={}", domain))
+                .filter(|(rule, _)| *rule == Rule::cookie_domain_attribute)
+                .and_then(|(_, value)| CookieDomain::parse(&value));
+
+            match parsed_domain {
+                Some(parsed_domain) => set_cookie.domain = Some(parsed_domain),
+                None => return Err(invalid_attribute_value("Domain", &domain)),
+            }
+        }
+
+        if let Some(path) = &set_cookie.path {
+            if has_control_characters(path)
+                || !is_attribute_value(Rule::cookie_path_attribute, "Path", path)
+            {
+                return Err(invalid_attribute_value("Path", path));
+            }
+        }
+
+        if let Some(same_site) = &set_cookie.same_site {
+            let value = same_site.as_str();
+
+            if has_control_characters(value)
+                || !is_attribute_value(Rule::cookie_samesite_attribute, "SameSite", value)
+            {
+                return Err(invalid_attribute_value("SameSite", value));
+            }
+        }
+
+        for extension in &set_cookie.extensions {
+            if has_control_characters(extension) || !is_extension(extension) {
+                return Err(invalid_attribute_value("extension", extension));
+            }
+        }
+
+        Ok(set_cookie)
+    }
+}
+
+fn matches_rule(rule: Rule, input: &str) -> bool {
+    Grammar::parse(rule, input).is_ok()
+}
+
+// Check that `<name>=<value>` parses as the given attribute with exactly `value` as its value.
+fn is_attribute_value(rule: Rule, name: &str, value: &str) -> bool {
+    attribute_value(&format!("{}={}", name, value))
+        .is_some_and(|(parsed_rule, parsed_value)| parsed_rule == rule && parsed_value == value)
+}
+
+// Check that the input parses as an extension attribute, and not as a known one.
+pub(crate) fn is_extension(input: &str) -> bool {
+    attribute_value(input)
+        .is_some_and(|(rule, value)| rule == Rule::cookie_extension_attribute && value == input)
+}
+
+// The alternative of `cookie_attribute` that matches the whole input, and its value. Extensions
+// have no separate value, so the whole attribute is returned for them.
+fn attribute_value(input: &str) -> Option<(Rule, String)> {
+    let inner_attribute = Grammar::parse(Rule::single_cookie_attribute, input)
+        .ok()?
+        .next()?
+        .into_inner()
+        .next()?
+        .into_inner()
+        .next()?;
+
+    let rule = inner_attribute.as_rule();
+    let value = inner_attribute
+        .clone()
+        .into_inner()
+        .last()
+        .map_or(inner_attribute.as_str(), |value| value.as_str());
+
+    Some((rule, String::from(value)))
+}
+
+// The grammar accepts any character but ';' in Path, SameSite and extension values, but
+// path-value and extension-av exclude CTLs, which would also allow header injection with CR/LF.
+// https://datatracker.ietf.org/doc/html/rfc6265#section-4.1.1
+fn has_control_characters(value: &str) -> bool {
+    value.chars().any(|c| c.is_ascii_control())
+}
+
+fn invalid_attribute_value(name: &str, value: &str) -> CookieBuildError {
+    CookieBuildError::ErrorInvalidAttributeValue {
+        name: String::from(name),
+        value: String::from(value),
+    }
+}
@@ -0,0 +1,109 @@
+// https://datatracker.ietf.org/doc/html/rfc6265#section-5.1.1
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// Parse a cookie-date (the value of the `Expires` attribute) into a UTC timestamp.
+///
+/// Implements the RFC 6265 §5.1.1 algorithm, so RFC 1123 (`Wed, 21 Oct 2015 07:28:00 GMT`),
+/// RFC 850 (`Wednesday, 21-Oct-15 07:28:00 GMT`) and asctime (`Wed Oct 21 07:28:00 2015`)
+/// dates are all accepted, as well as tokens in an unusual order. Returns `None` if the
+/// input is not a valid cookie-date.
+pub fn parse_cookie_date(input: &str) -> Option<DateTime<Utc>> {
+    let mut time: Option<(u32, u32, u32)> = None;
+    let mut day_of_month: Option<u32> = None;
+    let mut month: Option<u32> = None;
+    let mut year: Option<i32> = None;
+
+    for token in input.split(is_delimiter).filter(|token| !token.is_empty()) {
+        if time.is_none() {
+            if let Some(value) = parse_time(token) {
+                time = Some(value);
+                continue;
+            }
+        }
+
+        if day_of_month.is_none() {
+            if let Some(value) = parse_digits(token, 1, 2) {
+                day_of_month = Some(value);
+                continue;
+            }
+        }
+
+        if month.is_none() {
+            if let Some(value) = parse_month(token) {
+                month = Some(value);
+                continue;
+            }
+        }
+
+        if year.is_none() {
+            if let Some(value) = parse_digits(token, 2, 4) {
+                year = Some(value as i32);
+                continue;
+            }
+        }
+    }
+
+    let (hour, minute, second) = time?;
+    let day_of_month = day_of_month?;
+    let month = month?;
+    let year = match year? {
+        year @ 70..=99 => year + 1900,
+        year @ 0..=69 => year + 2000,
+        year => year,
+    };
+
+    if !(1..=31).contains(&day_of_month) || year < 1601 || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    NaiveDate::from_ymd_opt(year, month, day_of_month)?
+        .and_hms_opt(hour, minute, second)
+        .map(|date_time| date_time.and_utc())
+}
+
+// delimiter = %x09 / %x20-2F / %x3B-40 / %x5B-60 / %x7B-7E
+fn is_delimiter(c: char) -> bool {
+    matches!(c, '\x09' | '\x20'..='\x2F' | '\x3B'..='\x40' | '\x5B'..='\x60' | '\x7B'..='\x7E')
+}
+
+// Matches `min..=max` leading digits followed by either the end of the token or a non-digit.
+fn leading_digits(token: &str, min: usize, max: usize) -> Option<(u32, &str)> {
+    let digits = token.bytes().take_while(u8::is_ascii_digit).count();
+
+    if digits < min || digits > max {
+        return None;
+    }
+
+    let (digits, rest) = token.split_at(digits);
+
+    Some((digits.parse().ok()?, rest))
+}
+
+// day-of-month = 1*2DIGIT [ non-digit *OCTET ], year = 2*4DIGIT [ non-digit *OCTET ]
+fn parse_digits(token: &str, min: usize, max: usize) -> Option<u32> {
+    leading_digits(token, min, max).map(|(value, _)| value)
+}
+
+// time = hms-time [ non-digit *OCTET ], hms-time = time-field ":" time-field ":" time-field
+fn parse_time(token: &str) -> Option<(u32, u32, u32)> {
+    let (hour, rest) = leading_digits(token, 1, 2)?;
+    let (minute, rest) = leading_digits(rest.strip_prefix(':')?, 1, 2)?;
+    let (second, _) = leading_digits(rest.strip_prefix(':')?, 1, 2)?;
+
+    Some((hour, minute, second))
+}
+
+// month = ( "jan" / "feb" / ... / "dec" ) *OCTET
+fn parse_month(token: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+
+    let prefix = token.get(..3)?;
+
+    MONTHS
+        .iter()
+        .position(|month| month.eq_ignore_ascii_case(prefix))
+        .map(|index| index as u32 + 1)
+}
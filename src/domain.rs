@@ -0,0 +1,52 @@
+// https://datatracker.ietf.org/doc/html/rfc6265#section-5.2.3
+
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+/// The value of the `Domain` attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CookieDomain {
+    /// A host name, without the leading dot and lowercased.
+    Host(String),
+    /// An IPv4 address or a bracketed IPv6 literal.
+    Ip(IpAddr),
+}
+
+impl CookieDomain {
+    /// Interpret a `Domain` attribute value.
+    ///
+    /// As RFC 6265 §5.2.3 requires, a leading dot is ignored and the host name is lowercased.
+    /// IPv4 addresses and bracketed IPv6 literals (`[::1]`) are recognized as IP addresses.
+    /// Returns `None` for an empty value or a bracketed literal that is not an IPv6 address.
+    pub fn parse(input: &str) -> Option<CookieDomain> {
+        let domain = input.strip_prefix('.').unwrap_or(input);
+
+        if domain.is_empty() {
+            return None;
+        }
+
+        if let Some(literal) = domain.strip_prefix('[') {
+            let address = literal.strip_suffix(']')?.parse::<Ipv6Addr>().ok()?;
+
+            return Some(CookieDomain::Ip(IpAddr::V6(address)));
+        }
+
+        if let Ok(address) = domain.parse::<Ipv4Addr>() {
+            return Some(CookieDomain::Ip(IpAddr::V4(address)));
+        }
+
+        Some(CookieDomain::Host(domain.to_lowercase()))
+    }
+}
+
+impl fmt::Display for CookieDomain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CookieDomain::Host(host) => write!(f, "{}", host),
+            CookieDomain::Ip(IpAddr::V4(address)) => write!(f, "{}", address),
+            CookieDomain::Ip(IpAddr::V6(address)) => write!(f, "[{}]", address),
+        }
+    }
+}
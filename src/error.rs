@@ -0,0 +1,148 @@
+use std::fmt;
+
+use pest::{
+    error::{Error, ErrorVariant, InputLocation},
+    iterators::Pair,
+};
+
+use crate::Rule;
+
+/// The location and cause of a syntax error in a cookie/set-cookie string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyntaxError {
+    /// Byte offset into the input at which parsing failed.
+    pub offset: usize,
+    /// 1-based line of `offset`.
+    pub line: usize,
+    /// 1-based column of `offset`, counted in characters.
+    pub column: usize,
+    /// The offending part of the input, from `offset` up to the next `;` or line break.
+    pub snippet: String,
+    /// Descriptions of what could have appeared at `offset`, e.g. `cookie-value`.
+    pub expected: Vec<String>,
+    /// Names of the rules in grammar.pest that failed at `offset`, e.g. `cookie_value`.
+    /// Empty when the error was not produced by the grammar.
+    pub rules: Vec<String>,
+}
+
+impl SyntaxError {
+    pub(crate) fn new(input: &str, offset: usize, expected: Vec<String>) -> SyntaxError {
+        SyntaxError::with_rules(input, offset, expected, Vec::new())
+    }
+
+    fn with_rules(
+        input: &str,
+        offset: usize,
+        expected: Vec<String>,
+        rules: Vec<Rule>,
+    ) -> SyntaxError {
+        // Snap the offset down to a character boundary, so slicing the input cannot panic.
+        let mut offset = offset.min(input.len());
+
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        let rest = &input[offset..];
+        let first_len = rest.chars().next().map_or(0, char::len_utf8);
+        let snippet_len = rest[first_len..]
+            .find([';', '\r', '\n'])
+            .map_or(rest.len(), |index| index + first_len);
+
+        SyntaxError {
+            offset,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: String::from(rest[..snippet_len].trim_end_matches(['\r', '\n'])),
+            expected,
+            rules: rules.iter().map(|rule| format!("{:?}", rule)).collect(),
+        }
+    }
+
+    // `base` is the offset of the parsed text within `input`, for errors found while parsing a
+    // part of the input on its own.
+    pub(crate) fn from_pest(input: &str, base: usize, error: Error<Rule>) -> SyntaxError {
+        let offset = base
+            + match error.location {
+                InputLocation::Pos(offset) => offset,
+                InputLocation::Span((start, _)) => start,
+            };
+
+        let (expected, rules) = match error.variant {
+            ErrorVariant::ParsingError { positives, .. } => (
+                positives
+                    .iter()
+                    .map(|rule| String::from(describe_rule(*rule)))
+                    .collect(),
+                positives,
+            ),
+            ErrorVariant::CustomError { message } => (vec![message], Vec::new()),
+        };
+
+        SyntaxError::with_rules(input, offset, expected, rules)
+    }
+
+    // The grammar guarantees the shape of the parse tree, so this only guards against the tree
+    // walk getting out of sync with grammar.pest.
+    pub(crate) fn unexpected_structure(pair: &Pair<Rule>, expected: Rule) -> SyntaxError {
+        SyntaxError::with_rules(
+            pair.get_input(),
+            pair.as_span().start(),
+            vec![String::from(describe_rule(expected))],
+            vec![expected],
+        )
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.expected.is_empty() {
+            write!(f, "unexpected input")?;
+        } else {
+            write!(f, "expected {}", self.expected.join(" or "))?;
+        }
+
+        write!(f, " at line {}, column {}", self.line, self.column)?;
+
+        if !self.snippet.is_empty() {
+            write!(f, ", found '{}'", self.snippet)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Human-readable names for grammar rules, using the RFC 6265 terminology where there is one.
+fn describe_rule(rule: Rule) -> &'static str {
+    match rule {
+        Rule::EOI => "'; ' or end of input",
+        Rule::cookie_string => "cookie string",
+        Rule::set_cookie_string => "set-cookie string",
+        Rule::cookie_pair | Rule::set_cookie_pair => "cookie-pair",
+        Rule::cookie_name => "cookie-name",
+        Rule::cookie_value | Rule::cookie_quoted_value => "cookie-value",
+        Rule::cookie_attribute => "cookie attribute",
+        Rule::cookie_expires_attribute | Rule::cookie_expires_attribute_name => "Expires",
+        Rule::cookie_expires_attribute_value => "Expires date",
+        Rule::cookie_max_age_attribute | Rule::cookie_max_age_attribute_name => "Max-Age",
+        Rule::cookie_max_age_attribute_value => "Max-Age seconds",
+        Rule::cookie_domain_attribute | Rule::cookie_domain_attribute_name => "Domain",
+        Rule::cookie_domain_attribute_value => "Domain host name or IP address",
+        Rule::cookie_path_attribute | Rule::cookie_path_attribute_name => "Path",
+        Rule::cookie_path_attribute_value => "Path value",
+        Rule::cookie_secure_attribute => "Secure",
+        Rule::cookie_httponly_attribute => "HttpOnly",
+        Rule::cookie_samesite_attribute | Rule::cookie_samesite_attribute_name => "SameSite",
+        Rule::cookie_samesite_attribute_value => "SameSite value",
+        Rule::cookie_partitioned_attribute => "Partitioned",
+        Rule::cookie_priority_attribute | Rule::cookie_priority_attribute_name => "Priority",
+        Rule::cookie_priority_attribute_value => "Priority value",
+        Rule::cookie_extension_attribute => "extension attribute",
+        // Silent rules never produce parsing errors of their own.
+        _ => "valid input",
+    }
+}
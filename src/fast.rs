@@ -0,0 +1,440 @@
+// A hand-written parser for the strict grammar in grammar.pest.
+//
+// Every function below mirrors a rule of the grammar, including the parts of PEG semantics that
+// matter for which inputs are accepted: repetitions are greedy and never give characters back,
+// and the first alternative of a choice that matches is kept even if the rest of the input then
+// fails to parse.
+
+use pest::unicode::{DECIMAL_NUMBER, LETTER, MARK};
+
+use crate::{
+    parse_cookie_date, parse_max_age, CookieDomain, CookiePair, CookiePairRef, CookieParseError,
+    Priority, SameSite, SetCookie, SetCookieRef, SyntaxError,
+};
+
+/// Parse a cookie string like [`parse_cookie_string`](crate::parse_cookie_string), using a
+/// hand-written parser instead of the pest grammar.
+///
+/// The same inputs are accepted and the results are identical; for rejected inputs the error
+/// variant is the same, but the reported position and expectation may differ.
+pub fn parse_cookie_string_fast(input: &str) -> Result<Vec<CookiePair>, CookieParseError> {
+    let mut cursor = Cursor::new(input);
+    let mut cookie_pairs = vec![parse_cookie_pair(&mut cursor)?.into_owned()];
+
+    while !cursor.is_at_end() {
+        parse_delimiter(&mut cursor)?;
+        cookie_pairs.push(parse_cookie_pair(&mut cursor)?.into_owned());
+    }
+
+    Ok(cookie_pairs)
+}
+
+/// Parse a set-cookie string like [`parse_set_cookie`](crate::parse_set_cookie), using a
+/// hand-written parser instead of the pest grammar.
+///
+/// The same inputs are accepted and the results are identical; for rejected inputs the error
+/// variant is the same, but the reported position and expectation may differ.
+pub fn parse_set_cookie_fast(input: &str) -> Result<SetCookie, CookieParseError> {
+    let mut cursor = Cursor::new(input);
+    let mut set_cookie = SetCookieRef::from_pair(parse_cookie_pair(&mut cursor)?);
+
+    // The grammar is checked for the whole header before any attribute value is interpreted.
+    let mut invalid_value = None;
+
+    while !cursor.is_at_end() {
+        parse_delimiter(&mut cursor)?;
+
+        let attribute = parse_attribute(&mut cursor)?;
+
+        if invalid_value.is_none() {
+            invalid_value = apply_attribute(&mut set_cookie, attribute).err();
+        }
+    }
+
+    match invalid_value {
+        Some(err) => Err(err),
+        None => Ok(set_cookie.into_owned()),
+    }
+}
+
+struct Cursor<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Cursor<'a> {
+        Cursor { input, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position == self.input.len()
+    }
+
+    fn eat_byte(&mut self, byte: u8) -> bool {
+        let matched = self.rest().as_bytes().first() == Some(&byte);
+
+        if matched {
+            self.position += 1;
+        }
+
+        matched
+    }
+
+    // Only for ASCII predicates, so the match always ends on a character boundary.
+    fn eat_bytes(&mut self, predicate: impl Fn(u8) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.bytes().take_while(|&byte| predicate(byte)).count();
+
+        self.position += len;
+
+        &rest[..len]
+    }
+
+    // Consumes `len` bytes of `rest()`, which must end on a character boundary.
+    fn take(&mut self, len: usize) -> &'a str {
+        let taken = &self.rest()[..len];
+
+        self.position += len;
+
+        taken
+    }
+
+    fn error(&self, expected: &str) -> CookieParseError {
+        self.error_at(self.position, expected)
+    }
+
+    fn error_at(&self, offset: usize, expected: &str) -> CookieParseError {
+        CookieParseError::ErrorCookieStringSyntax(SyntaxError::new(
+            self.input,
+            offset,
+            vec![String::from(expected)],
+        ))
+    }
+}
+
+// ";" ~ WHITE_SPACE
+fn parse_delimiter(cursor: &mut Cursor<'_>) -> Result<(), CookieParseError> {
+    if !cursor.eat_byte(b';') {
+        return Err(cursor.error("'; ' or end of input"));
+    }
+
+    match cursor.rest().chars().next() {
+        Some(c) if c.is_whitespace() => {
+            cursor.take(c.len_utf8());
+
+            Ok(())
+        }
+        _ => Err(cursor.error("' ' after ';'")),
+    }
+}
+
+// cookie_pair = { cookie_name ~ "=" ~ cookie_value? }
+// set_cookie_pair = { cookie_name ~ "=" ~ cookie_value? }
+fn parse_cookie_pair<'a>(cursor: &mut Cursor<'a>) -> Result<CookiePairRef<'a>, CookieParseError> {
+    let name = cursor.eat_bytes(|byte| !is_separator(byte));
+
+    if name.is_empty() {
+        return Err(cursor.error("cookie-name"));
+    }
+
+    if !cursor.eat_byte(b'=') {
+        return Err(cursor.error("'='"));
+    }
+
+    let value = cursor.eat_bytes(is_cookie_octet);
+
+    if !value.is_empty() {
+        return Ok(CookiePairRef {
+            name,
+            value,
+            quoted: false,
+        });
+    }
+
+    if !cursor.eat_byte(b'"') {
+        return Ok(CookiePairRef {
+            name,
+            value,
+            quoted: false,
+        });
+    }
+
+    let value = cursor.eat_bytes(is_cookie_octet);
+
+    if value.is_empty() {
+        return Err(cursor.error("cookie-value"));
+    }
+
+    if !cursor.eat_byte(b'"') {
+        return Err(cursor.error("'\"'"));
+    }
+
+    Ok(CookiePairRef {
+        name,
+        value,
+        quoted: true,
+    })
+}
+
+enum Attribute<'a> {
+    Expires(&'a str),
+    MaxAge(&'a str),
+    Domain { name: &'a str, value: &'a str },
+    Path(&'a str),
+    Secure,
+    HttpOnly,
+    SameSite(&'a str),
+    Partitioned,
+    Priority(&'a str),
+    Extension(&'a str),
+}
+
+// cookie_attribute
+fn parse_attribute<'a>(cursor: &mut Cursor<'a>) -> Result<Attribute<'a>, CookieParseError> {
+    // No alternative can match past the next ";".
+    let rest = cursor.rest();
+    let segment = &rest[..rest.find(';').unwrap_or(rest.len())];
+
+    let (len, attribute) =
+        match_attribute(segment).ok_or_else(|| cursor.error("cookie attribute"))?;
+
+    // The matched alternative is kept, so anything left before the ";" is an error.
+    if len != segment.len() {
+        return Err(cursor.error_at(cursor.position + len, "'; ' or end of input"));
+    }
+
+    cursor.take(len);
+
+    Ok(attribute)
+}
+
+// Tries the alternatives of cookie_attribute in order, returning the length of the first match.
+fn match_attribute(segment: &str) -> Option<(usize, Attribute<'_>)> {
+    if let Some((_, offset)) = match_valued(segment, "Expires") {
+        if offset < segment.len() {
+            return Some((segment.len(), Attribute::Expires(&segment[offset..])));
+        }
+    }
+
+    if let Some((_, offset)) = match_valued(segment, "Max-Age") {
+        let value = &segment[offset..];
+        let sign = usize::from(value.starts_with('-'));
+        let digits = value[sign..].bytes().take_while(u8::is_ascii_digit).count();
+
+        if digits > 0 {
+            let len = sign + digits;
+
+            return Some((offset + len, Attribute::MaxAge(&value[..len])));
+        }
+    }
+
+    if let Some((name, offset)) = match_valued(segment, "Domain") {
+        if let Some(len) = match_domain_value(&segment[offset..]) {
+            let value = &segment[offset..offset + len];
+
+            return Some((offset + len, Attribute::Domain { name, value }));
+        }
+    }
+
+    if let Some((_, offset)) = match_valued(segment, "Path") {
+        if offset < segment.len() {
+            return Some((segment.len(), Attribute::Path(&segment[offset..])));
+        }
+    }
+
+    if segment.eq_ignore_ascii_case("Secure") {
+        return Some((segment.len(), Attribute::Secure));
+    }
+
+    if segment.eq_ignore_ascii_case("HttpOnly") {
+        return Some((segment.len(), Attribute::HttpOnly));
+    }
+
+    if let Some((_, offset)) = match_valued(segment, "SameSite") {
+        let value = segment[offset..].trim_end_matches([' ', '\t']);
+
+        if !value.is_empty() {
+            return Some((segment.len(), Attribute::SameSite(value)));
+        }
+    }
+
+    if segment.eq_ignore_ascii_case("Partitioned") {
+        return Some((segment.len(), Attribute::Partitioned));
+    }
+
+    if let Some((_, offset)) = match_valued(segment, "Priority") {
+        if offset < segment.len() {
+            return Some((segment.len(), Attribute::Priority(&segment[offset..])));
+        }
+    }
+
+    is_extension(segment).then_some((segment.len(), Attribute::Extension(segment)))
+}
+
+// !(cookie_known_direct_attributes) ~ !(cookie_known_value_attributes ~ OWS ~ "=" ~ (!";" ~ ANY)+)
+//   ~ ((!";" ~ ANY)+)
+fn is_extension(segment: &str) -> bool {
+    let is_known_direct = ["HttpOnly", "Secure", "Partitioned"]
+        .iter()
+        .any(|name| segment.eq_ignore_ascii_case(name));
+
+    if is_known_direct {
+        return false;
+    }
+
+    let known_value = [
+        "Expires", "Max-Age", "Domain", "Path", "SameSite", "Priority",
+    ]
+    .iter()
+    .find_map(|name| match_insensitive(segment, name));
+
+    if let Some(name) = known_value {
+        let rest = skip_ows(&segment[name.len()..]);
+
+        if rest
+            .strip_prefix('=')
+            .is_some_and(|value| !value.is_empty())
+        {
+            return false;
+        }
+    }
+
+    !segment.is_empty()
+}
+
+// `^"Name" ~ OWS ~ "=" ~ OWS`, returning the name as spelled and the offset of the value.
+fn match_valued<'a>(segment: &'a str, attribute_name: &str) -> Option<(&'a str, usize)> {
+    let name = match_insensitive(segment, attribute_name)?;
+    let rest = skip_ows(&segment[name.len()..]);
+    let value = skip_ows(rest.strip_prefix('=')?);
+
+    Some((name, segment.len() - value.len()))
+}
+
+// ^"literal", which pest matches with an ASCII case-insensitive comparison.
+fn match_insensitive<'a>(input: &'a str, literal: &str) -> Option<&'a str> {
+    input
+        .get(..literal.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(literal))
+}
+
+// OWS = _{ (" " | "\t")* }
+fn skip_ows(input: &str) -> &str {
+    input.trim_start_matches([' ', '\t'])
+}
+
+// cookie_domain_attribute_value = { "."? ~ (cookie_domain_ip_literal | cookie_domain_host_name) }
+fn match_domain_value(input: &str) -> Option<usize> {
+    let dot = usize::from(input.starts_with('.'));
+    let rest = &input[dot..];
+
+    match_ip_literal(rest)
+        .or_else(|| match_host_name(rest))
+        .map(|len| dot + len)
+}
+
+// cookie_domain_ip_literal = _{ "[" ~ (ASCII_HEX_DIGIT | ":" | ".")+ ~ "]" }
+fn match_ip_literal(input: &str) -> Option<usize> {
+    let literal = input.strip_prefix('[')?;
+    let len = literal
+        .bytes()
+        .take_while(|&byte| byte.is_ascii_hexdigit() || byte == b':' || byte == b'.')
+        .count();
+
+    (len > 0 && literal[len..].starts_with(']')).then_some(len + 2)
+}
+
+// cookie_domain_host_name = _{ cookie_domain_label ~ (("." ~ cookie_domain_label) *) }
+fn match_host_name(input: &str) -> Option<usize> {
+    let mut len = match_label(input)?;
+
+    while let Some(label_len) = input[len..].strip_prefix('.').and_then(match_label) {
+        len += 1 + label_len;
+    }
+
+    Some(len)
+}
+
+// cookie_domain_label = _{ (LETTER | MARK | DECIMAL_NUMBER | "-")+ }
+fn match_label(input: &str) -> Option<usize> {
+    let len = input
+        .chars()
+        .take_while(|&c| {
+            if c.is_ascii() {
+                c.is_ascii_alphanumeric() || c == '-'
+            } else {
+                LETTER(c) || MARK(c) || DECIMAL_NUMBER(c)
+            }
+        })
+        .map(char::len_utf8)
+        .sum();
+
+    (len > 0).then_some(len)
+}
+
+// The same interpretation of attribute values as `apply_attribute` for the pest parse tree.
+fn apply_attribute<'a>(
+    set_cookie: &mut SetCookieRef<'a>,
+    attribute: Attribute<'a>,
+) -> Result<(), CookieParseError> {
+    match attribute {
+        Attribute::Expires(value) => {
+            if let Some(expires) = parse_cookie_date(value) {
+                set_cookie.expires = Some(expires);
+            }
+        }
+        Attribute::MaxAge(value) => set_cookie.max_age = Some(parse_max_age(value)),
+        Attribute::Domain { name, value } => {
+            let domain = CookieDomain::parse(value).ok_or_else(|| {
+                CookieParseError::ErrorInvalidAttributeValue {
+                    name: String::from(name),
+                    value: String::from(value),
+                }
+            })?;
+
+            set_cookie.domain = Some(domain);
+        }
+        Attribute::Path(value) => set_cookie.path = Some(value),
+        Attribute::Secure => set_cookie.secure = true,
+        Attribute::HttpOnly => set_cookie.http_only = true,
+        Attribute::SameSite(value) => set_cookie.same_site = Some(SameSite::parse(value)),
+        Attribute::Partitioned => set_cookie.partitioned = true,
+        Attribute::Priority(value) => set_cookie.priority = Priority::parse(value),
+        Attribute::Extension(value) => set_cookie.extensions.push(value),
+    }
+
+    Ok(())
+}
+
+// separator = _{ "(" | ")" | "<" | ">" | "@" | "," | ";" | ":" | "\\" | "\"" | "/" | "[" | "]" | "?" | "=" | "{" | "}" }
+fn is_separator(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'(' | b')'
+            | b'<'
+            | b'>'
+            | b'@'
+            | b','
+            | b';'
+            | b':'
+            | b'\\'
+            | b'"'
+            | b'/'
+            | b'['
+            | b']'
+            | b'?'
+            | b'='
+            | b'{'
+            | b'}'
+    )
+}
+
+// cookie_octet = _{ "\x21" | '\x23'..'\x2B' | '\x2D'..'\x3A' | '\x3C'..'\x5B' | '\x5D'..'\x7E' }
+fn is_cookie_octet(byte: u8) -> bool {
+    matches!(byte, 0x21 | 0x23..=0x2B | 0x2D..=0x3A | 0x3C..=0x5B | 0x5D..=0x7E)
+}
@@ -0,0 +1,123 @@
+use std::slice;
+
+use crate::{parse_cookie_string, CookiePair, CookieParseError};
+
+/// How a [`CookieHeader`] treats cookies that share a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DuplicatePolicy {
+    /// [`CookieHeader::get`] returns the first cookie with the name. User agents send the cookie
+    /// with the longest path first, so this picks the most specific one.
+    #[default]
+    FirstWins,
+    /// [`CookieHeader::get`] returns the last cookie with the name.
+    LastWins,
+    /// Building the header fails with `CookieParseError::ErrorDuplicateCookieName`.
+    Error,
+}
+
+/// The cookie pairs of a Cookie header, in the order they were sent, with lookup by name.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "crate::serde_impls::CookieHeaderFields")
+)]
+pub struct CookieHeader {
+    pairs: Vec<CookiePair>,
+    policy: DuplicatePolicy,
+}
+
+impl CookieHeader {
+    /// Parse a cookie string with [`parse_cookie_string`], keeping the first cookie of each name.
+    pub fn parse(input: &str) -> Result<CookieHeader, CookieParseError> {
+        CookieHeader::parse_with_policy(input, DuplicatePolicy::default())
+    }
+
+    /// Parse a cookie string with [`parse_cookie_string`], resolving duplicate names with `policy`.
+    pub fn parse_with_policy(
+        input: &str,
+        policy: DuplicatePolicy,
+    ) -> Result<CookieHeader, CookieParseError> {
+        CookieHeader::from_pairs(parse_cookie_string(input)?, policy)
+    }
+
+    /// Collect already parsed cookie pairs, resolving duplicate names with `policy`.
+    pub fn from_pairs(
+        pairs: Vec<CookiePair>,
+        policy: DuplicatePolicy,
+    ) -> Result<CookieHeader, CookieParseError> {
+        if policy == DuplicatePolicy::Error {
+            for (index, pair) in pairs.iter().enumerate() {
+                if pairs[..index].iter().any(|other| other.name == pair.name) {
+                    return Err(CookieParseError::ErrorDuplicateCookieName {
+                        name: pair.name.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(CookieHeader { pairs, policy })
+    }
+
+    /// The cookie with the given name, chosen by the duplicate policy. Names are case-sensitive.
+    pub fn get(&self, name: &str) -> Option<&CookiePair> {
+        let mut pairs = self.pairs.iter().filter(|pair| pair.name == name);
+
+        match self.policy {
+            DuplicatePolicy::LastWins => pairs.next_back(),
+            DuplicatePolicy::FirstWins | DuplicatePolicy::Error => pairs.next(),
+        }
+    }
+
+    /// Every cookie with the given name, in header order.
+    pub fn get_all<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl DoubleEndedIterator<Item = &'a CookiePair> + 'a {
+        self.pairs.iter().filter(move |pair| pair.name == name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.pairs.iter().any(|pair| pair.name == name)
+    }
+
+    /// All cookies in header order, including duplicates.
+    pub fn iter(&self) -> slice::Iter<'_, CookiePair> {
+        self.pairs.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    pub fn policy(&self) -> DuplicatePolicy {
+        self.policy
+    }
+
+    pub fn into_pairs(self) -> Vec<CookiePair> {
+        self.pairs
+    }
+}
+
+impl<'a> IntoIterator for &'a CookieHeader {
+    type Item = &'a CookiePair;
+    type IntoIter = slice::Iter<'a, CookiePair>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for CookieHeader {
+    type Item = CookiePair;
+    type IntoIter = std::vec::IntoIter<CookiePair>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.pairs.into_iter()
+    }
+}
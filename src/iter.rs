@@ -0,0 +1,94 @@
+use std::iter::FusedIterator;
+
+use pest::Parser;
+
+use crate::{
+    parse_cookie_pair, unexpected_structure, CookiePairRef, CookieParseError, Grammar, Rule,
+    SyntaxError,
+};
+
+/// Lazily parse a cookie string, yielding each cookie pair as it is reached.
+///
+/// Every `; `-separated pair is checked against the strict grammar on its own, so a malformed
+/// pair produces an `Err` item for that pair only and iteration can continue with the next one.
+/// Nothing past the last pair pulled from the iterator is parsed.
+pub fn parse_cookie_string_iter(input: &str) -> CookiePairIter<'_> {
+    CookiePairIter {
+        input,
+        position: Some(0),
+    }
+}
+
+/// Iterator over the cookie pairs of a cookie string, returned by [`parse_cookie_string_iter`].
+#[derive(Debug, Clone)]
+pub struct CookiePairIter<'a> {
+    input: &'a str,
+    // Byte offset of the next segment, or `None` once the input is exhausted.
+    position: Option<usize>,
+}
+
+impl<'a> CookiePairIter<'a> {
+    fn parse_segment(
+        &self,
+        start: usize,
+        end: usize,
+    ) -> Result<CookiePairRef<'a>, CookieParseError> {
+        let segment = &self.input[start..end];
+
+        let single_cookie_pair = Grammar::parse(Rule::single_cookie_pair, segment)
+            .map_err(|err| {
+                CookieParseError::ErrorCookieStringSyntax(SyntaxError::from_pest(
+                    self.input, start, err,
+                ))
+            })?
+            .next()
+            .ok_or(CookieParseError::ErrorCookieStringEmpty)?;
+
+        let cookie_pair = single_cookie_pair
+            .clone()
+            .into_inner()
+            .next()
+            .ok_or_else(|| unexpected_structure(&single_cookie_pair, Rule::cookie_pair))?;
+
+        parse_cookie_pair(&cookie_pair)
+    }
+}
+
+impl<'a> Iterator for CookiePairIter<'a> {
+    type Item = Result<CookiePairRef<'a>, CookieParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let segment_start = self.position?;
+
+        let segment_end = match self.input[segment_start..].find(';') {
+            Some(index) => {
+                self.position = Some(segment_start + index + 1);
+                segment_start + index
+            }
+            None => {
+                self.position = None;
+                self.input.len()
+            }
+        };
+
+        if segment_start == 0 {
+            return Some(self.parse_segment(segment_start, segment_end));
+        }
+
+        // Mirror `";" ~ WHITE_SPACE ~ cookie_pair`.
+        match self.input[segment_start..segment_end].chars().next() {
+            Some(c) if c.is_whitespace() => {
+                Some(self.parse_segment(segment_start + c.len_utf8(), segment_end))
+            }
+            _ => Some(Err(CookieParseError::ErrorCookieStringSyntax(
+                SyntaxError::new(
+                    self.input,
+                    segment_start,
+                    vec![String::from("' ' after ';'")],
+                ),
+            ))),
+        }
+    }
+}
+
+impl FusedIterator for CookiePairIter<'_> {}
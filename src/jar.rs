@@ -0,0 +1,348 @@
+// https://datatracker.ietf.org/doc/html/rfc6265#section-5.3
+
+use std::slice;
+#[cfg(feature = "psl")]
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+use url::Url;
+
+use crate::{
+    matching::{canonicalize_domain, default_path, is_domain_match, path_match},
+    CookieHeader, CookiePair, DuplicatePolicy, SameSite, SetCookie,
+};
+#[cfg(feature = "psl")]
+use crate::{PublicSuffixCheck, PublicSuffixList};
+
+/// The kind of API a cookie is set or read through. HttpOnly cookies are only available to
+/// HTTP APIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ApiContext {
+    /// Set-Cookie and Cookie headers.
+    #[default]
+    Http,
+    /// Scripting APIs such as `document.cookie`.
+    NonHttp,
+}
+
+/// A reason for [`CookieJar::insert`] to ignore a cookie.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CookieJarError {
+    #[error("Request URL '{0}' has no host")]
+    ErrorNoHost(String),
+    #[error("Domain '{domain}' does not domain-match request host '{host}'")]
+    ErrorDomainMismatch { domain: String, host: String },
+    #[error("HttpOnly cookie '{0}' cannot be set from a non-HTTP API")]
+    ErrorHttpOnlyFromNonHttpApi(String),
+    #[error("Cookie '{0}' would replace an HttpOnly cookie from a non-HTTP API")]
+    ErrorReplacesHttpOnly(String),
+    #[cfg(feature = "psl")]
+    #[error("Domain '{domain}' is a public suffix and cannot be set by request host '{host}'")]
+    ErrorPublicSuffixDomain { domain: String, host: String },
+}
+
+/// A cookie in a [`CookieJar`], with the fields of the RFC 6265 §5.3 storage model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StoredCookie {
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serde_impls::unchecked_cookie_pair")
+    )]
+    pub pair: CookiePair,
+    /// The canonicalized host name or IP address the cookie belongs to.
+    pub domain: String,
+    pub path: String,
+    /// `None` for session cookies, which have no persistent-flag in the storage model.
+    pub expiry_time: Option<DateTime<Utc>>,
+    pub creation_time: DateTime<Utc>,
+    pub last_access_time: DateTime<Utc>,
+    /// Whether the cookie is only sent to `domain` itself, because it had no `Domain` attribute.
+    pub host_only: bool,
+    pub secure_only: bool,
+    pub http_only: bool,
+    pub same_site: Option<SameSite>,
+}
+
+impl StoredCookie {
+    /// Whether the cookie outlives the session, i.e. it had `Max-Age` or `Expires`.
+    pub fn is_persistent(&self) -> bool {
+        self.expiry_time.is_some()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expiry_time
+            .is_some_and(|expiry_time| expiry_time <= now)
+    }
+}
+
+/// A cookie store following the RFC 6265 §5.3 storage model.
+///
+/// Times are passed in explicitly, so the jar can be used with a simulated clock.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CookieJar {
+    cookies: Vec<StoredCookie>,
+    #[cfg(feature = "psl")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    public_suffix_list: Option<Arc<PublicSuffixList>>,
+}
+
+impl CookieJar {
+    pub fn new() -> CookieJar {
+        CookieJar::default()
+    }
+
+    #[cfg(feature = "persist")]
+    pub(crate) fn from_cookies(cookies: Vec<StoredCookie>) -> CookieJar {
+        CookieJar {
+            cookies,
+            #[cfg(feature = "psl")]
+            public_suffix_list: None,
+        }
+    }
+
+    #[cfg(feature = "persist")]
+    pub(crate) fn into_cookies(self) -> Vec<StoredCookie> {
+        self.cookies
+    }
+
+    /// Ignore cookies whose `Domain` attribute is a public suffix in `list`, as browsers do.
+    ///
+    /// A cookie for a public suffix is only stored when the request host is that suffix itself,
+    /// and then as a host-only cookie. Other ones are rejected with
+    /// `CookieJarError::ErrorPublicSuffixDomain`. The list is not serialized with the jar.
+    #[cfg(feature = "psl")]
+    pub fn with_public_suffix_list(mut self, list: impl Into<Arc<PublicSuffixList>>) -> CookieJar {
+        self.public_suffix_list = Some(list.into());
+        self
+    }
+
+    /// Store a cookie received in a Set-Cookie header of a response to `request_url`.
+    ///
+    /// A cookie with the same name, domain and path replaces the stored one but keeps its
+    /// creation time. An expired cookie removes the stored one without being stored itself.
+    pub fn insert(
+        &mut self,
+        set_cookie: SetCookie,
+        request_url: &Url,
+        api: ApiContext,
+        now: DateTime<Utc>,
+    ) -> Result<(), CookieJarError> {
+        let request_host = request_url
+            .host_str()
+            .ok_or_else(|| CookieJarError::ErrorNoHost(request_url.to_string()))?;
+
+        #[cfg(feature = "psl")]
+        let set_cookie = self.apply_public_suffix_list(set_cookie, request_host)?;
+
+        let (domain, host_only) = match &set_cookie.domain {
+            Some(domain_attribute) => {
+                let domain = canonicalize_domain(domain_attribute).ok_or_else(|| {
+                    CookieJarError::ErrorDomainMismatch {
+                        domain: domain_attribute.to_string(),
+                        host: String::from(request_host),
+                    }
+                })?;
+
+                if !is_domain_match(request_host, &domain) {
+                    return Err(CookieJarError::ErrorDomainMismatch {
+                        domain,
+                        host: String::from(request_host),
+                    });
+                }
+
+                (domain, false)
+            }
+            None => (String::from(request_host), true),
+        };
+
+        let path = match &set_cookie.path {
+            Some(path) if path.starts_with('/') => path.clone(),
+            _ => String::from(default_path(request_url.path())),
+        };
+
+        if api == ApiContext::NonHttp && set_cookie.http_only {
+            return Err(CookieJarError::ErrorHttpOnlyFromNonHttpApi(
+                set_cookie.pair.name,
+            ));
+        }
+
+        let mut cookie = StoredCookie {
+            expiry_time: set_cookie.expiry_time(now),
+            pair: set_cookie.pair,
+            domain,
+            path,
+            creation_time: now,
+            last_access_time: now,
+            host_only,
+            secure_only: set_cookie.secure,
+            http_only: set_cookie.http_only,
+            same_site: set_cookie.same_site,
+        };
+
+        let old_index = self.cookies.iter().position(|old| {
+            old.pair.name == cookie.pair.name
+                && old.domain == cookie.domain
+                && old.path == cookie.path
+        });
+
+        if let Some(old_index) = old_index {
+            if api == ApiContext::NonHttp && self.cookies[old_index].http_only {
+                return Err(CookieJarError::ErrorReplacesHttpOnly(cookie.pair.name));
+            }
+
+            cookie.creation_time = self.cookies.remove(old_index).creation_time;
+        }
+
+        if !cookie.is_expired(now) {
+            self.cookies.push(cookie);
+        }
+
+        Ok(())
+    }
+
+    /// The cookies to send with a request to `request_url`, following RFC 6265 §5.4.
+    ///
+    /// Cookies are selected by domain, path, the `Secure` flag (only sent over `https` and `wss`)
+    /// and the `HttpOnly` flag (only sent to `ApiContext::Http`). Those with longer paths come
+    /// first, then those created earlier. Expired cookies are removed and the last-access time of
+    /// the returned ones is set to `now`.
+    pub fn cookies_for(
+        &mut self,
+        request_url: &Url,
+        api: ApiContext,
+        now: DateTime<Utc>,
+    ) -> Vec<CookiePair> {
+        self.remove_expired(now);
+
+        let Some(request_host) = request_url.host_str() else {
+            return vec![];
+        };
+
+        let request_path = request_url.path();
+        let is_secure = matches!(request_url.scheme(), "https" | "wss");
+
+        let mut cookies: Vec<&mut StoredCookie> = self
+            .cookies
+            .iter_mut()
+            .filter(|cookie| {
+                let domain_matches = if cookie.host_only {
+                    request_host == cookie.domain
+                } else {
+                    is_domain_match(request_host, &cookie.domain)
+                };
+
+                domain_matches
+                    && path_match(request_path, &cookie.path)
+                    && (is_secure || !cookie.secure_only)
+                    && (api == ApiContext::Http || !cookie.http_only)
+            })
+            .collect();
+
+        cookies.sort_by(|a, b| {
+            b.path
+                .len()
+                .cmp(&a.path.len())
+                .then(a.creation_time.cmp(&b.creation_time))
+        });
+
+        cookies
+            .into_iter()
+            .map(|cookie| {
+                cookie.last_access_time = now;
+                cookie.pair.clone()
+            })
+            .collect()
+    }
+
+    /// The Cookie header to send with a request to `request_url`, or `None` when no cookie
+    /// applies. See [`cookies_for`](Self::cookies_for).
+    ///
+    /// The header lists cookies that share a name with the most specific one first, so
+    /// [`CookieHeader::get`] returns that one.
+    pub fn cookie_header(
+        &mut self,
+        request_url: &Url,
+        api: ApiContext,
+        now: DateTime<Utc>,
+    ) -> Option<CookieHeader> {
+        let cookies = self.cookies_for(request_url, api, now);
+
+        if cookies.is_empty() {
+            return None;
+        }
+
+        CookieHeader::from_pairs(cookies, DuplicatePolicy::FirstWins).ok()
+    }
+
+    // https://datatracker.ietf.org/doc/html/rfc6265#section-5.3 step 5
+    #[cfg(feature = "psl")]
+    fn apply_public_suffix_list(
+        &self,
+        mut set_cookie: SetCookie,
+        request_host: &str,
+    ) -> Result<SetCookie, CookieJarError> {
+        let Some(list) = &self.public_suffix_list else {
+            return Ok(set_cookie);
+        };
+
+        match set_cookie.check_public_suffix(request_host, list) {
+            PublicSuffixCheck::Allowed => {}
+            PublicSuffixCheck::HostOnly => set_cookie.domain = None,
+            PublicSuffixCheck::Rejected => {
+                return Err(CookieJarError::ErrorPublicSuffixDomain {
+                    domain: set_cookie
+                        .domain
+                        .map(|domain| domain.to_string())
+                        .unwrap_or_default(),
+                    host: String::from(request_host),
+                })
+            }
+        }
+
+        Ok(set_cookie)
+    }
+
+    /// Remove every cookie whose expiry time has passed.
+    pub fn remove_expired(&mut self, now: DateTime<Utc>) {
+        self.cookies.retain(|cookie| !cookie.is_expired(now));
+    }
+
+    /// Remove every session cookie, as user agents do when the session ends.
+    pub fn end_session(&mut self) {
+        self.cookies.retain(StoredCookie::is_persistent);
+    }
+
+    /// The stored cookie with the given name, domain and path.
+    pub fn get(&self, name: &str, domain: &str, path: &str) -> Option<&StoredCookie> {
+        self.cookies.iter().find(|cookie| {
+            cookie.pair.name == name && cookie.domain == domain && cookie.path == path
+        })
+    }
+
+    /// All stored cookies, including expired ones that have not been removed yet.
+    pub fn iter(&self) -> slice::Iter<'_, StoredCookie> {
+        self.cookies.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cookies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cookies.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a CookieJar {
+    type Item = &'a StoredCookie;
+    type IntoIter = slice::Iter<'a, StoredCookie>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
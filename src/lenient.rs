@@ -0,0 +1,140 @@
+// https://datatracker.ietf.org/doc/html/rfc6265#section-5.2
+
+use crate::{
+    parse_cookie_date, parse_max_age, CookieDomain, CookiePair, CookiePairRef, CookieParseError,
+    Priority, SameSite, SetCookie, SyntaxError,
+};
+
+/// Parse a cookie string into a vector of cookie pairs, tolerating the formatting produced by
+/// browsers and proxies.
+///
+/// Pairs are split on `;` and surrounding whitespace is trimmed, so `a=1;b=2`, `a=1;  b=2`,
+/// a trailing `;` and leading/trailing spaces are all accepted. Segments without a `=` or with
+/// an empty name are skipped.
+pub fn parse_cookie_string_lenient(input: &str) -> Result<Vec<CookiePair>, CookieParseError> {
+    let cookie_pairs: Vec<CookiePair> = input
+        .split(';')
+        .filter_map(parse_cookie_pair)
+        .map(CookiePairRef::into_owned)
+        .collect();
+
+    if cookie_pairs.is_empty() {
+        return Err(CookieParseError::ErrorCookieStringEmpty);
+    }
+
+    Ok(cookie_pairs)
+}
+
+/// Parse a set-cookie string into a set-cookie structure following the RFC 6265 §5.2 user agent
+/// algorithm.
+///
+/// Whitespace around `;` and `=` is trimmed and attributes with values that cannot be
+/// interpreted are ignored instead of failing the whole header. The header is only rejected when
+/// its name-value pair lacks a `=` or has an empty name.
+pub fn parse_set_cookie_lenient(input: &str) -> Result<SetCookie, CookieParseError> {
+    if trim_whitespace(input).is_empty() {
+        return Err(CookieParseError::ErrorCookieStringEmpty);
+    }
+
+    let (name_value_pair, unparsed_attributes) = match input.split_once(';') {
+        Some((name_value_pair, unparsed_attributes)) => (name_value_pair, unparsed_attributes),
+        None => (input, ""),
+    };
+
+    let cookie_pair = parse_cookie_pair(name_value_pair).ok_or_else(|| {
+        // The name-value pair is a prefix of the input, so offsets into it are input offsets.
+        let (offset, expected) = if name_value_pair.contains('=') {
+            let offset =
+                name_value_pair.len() - name_value_pair.trim_start_matches([' ', '\t']).len();
+
+            (offset, "cookie-name")
+        } else {
+            (name_value_pair.trim_end().len(), "'='")
+        };
+
+        CookieParseError::ErrorCookieStringSyntax(SyntaxError::new(
+            input,
+            offset,
+            vec![String::from(expected)],
+        ))
+    })?;
+
+    let mut set_cookie = SetCookie::from_pair(cookie_pair.into_owned());
+
+    for cookie_av in unparsed_attributes.split(';') {
+        let cookie_av = trim_whitespace(cookie_av);
+
+        if cookie_av.is_empty() {
+            continue;
+        }
+
+        let (name, value) = match cookie_av.split_once('=') {
+            Some((name, value)) => (trim_whitespace(name), trim_whitespace(value)),
+            None => (cookie_av, ""),
+        };
+
+        if name.eq_ignore_ascii_case("Expires") {
+            if let Some(expires) = parse_cookie_date(value) {
+                set_cookie.expires = Some(expires);
+            }
+        } else if name.eq_ignore_ascii_case("Max-Age") {
+            if is_delta_seconds(value) {
+                set_cookie.max_age = Some(parse_max_age(value));
+            }
+        } else if name.eq_ignore_ascii_case("Domain") {
+            if let Some(domain) = CookieDomain::parse(value) {
+                set_cookie.domain = Some(domain);
+            }
+        } else if name.eq_ignore_ascii_case("Path") {
+            set_cookie.path = value.starts_with('/').then(|| String::from(value));
+        } else if name.eq_ignore_ascii_case("Secure") {
+            set_cookie.secure = true;
+        } else if name.eq_ignore_ascii_case("HttpOnly") {
+            set_cookie.http_only = true;
+        } else if name.eq_ignore_ascii_case("SameSite") {
+            set_cookie.same_site = Some(SameSite::parse(value));
+        } else if name.eq_ignore_ascii_case("Partitioned") {
+            set_cookie.partitioned = true;
+        } else if name.eq_ignore_ascii_case("Priority") {
+            if let Some(priority) = Priority::parse(value) {
+                set_cookie.priority = Some(priority);
+            }
+        } else {
+            set_cookie.extensions.push(String::from(cookie_av));
+        }
+    }
+
+    Ok(set_cookie)
+}
+
+pub(crate) fn parse_cookie_pair(input: &str) -> Option<CookiePairRef<'_>> {
+    let (name, value) = input.split_once('=')?;
+    let name = trim_whitespace(name);
+    let value = trim_whitespace(value);
+
+    if name.is_empty() {
+        return None;
+    }
+
+    let quoted_value = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'));
+
+    Some(CookiePairRef {
+        name,
+        value: quoted_value.unwrap_or(value),
+        quoted: quoted_value.is_some(),
+    })
+}
+
+// If the first character is not a DIGIT or "-", or any remaining character is not a DIGIT,
+// the Max-Age attribute is ignored.
+fn is_delta_seconds(input: &str) -> bool {
+    let digits = input.strip_prefix('-').unwrap_or(input);
+
+    !digits.is_empty() && digits.bytes().all(|c| c.is_ascii_digit())
+}
+
+fn trim_whitespace(input: &str) -> &str {
+    input.trim_matches(|c| c == ' ' || c == '\t')
+}
@@ -1,180 +1,463 @@
 #![doc = include_str!("../doc.md")]
 
+mod borrowed;
+mod builder;
+mod date;
+mod domain;
+mod error;
+mod fast;
+mod header;
+mod iter;
+mod jar;
+mod lenient;
+mod matching;
+#[cfg(feature = "persist")]
+mod persist;
+#[cfg(feature = "psl")]
+mod psl;
+mod recover;
+mod removal;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod serialize;
+
+use chrono::{DateTime, Duration, Utc};
 use pest::{iterators::Pair, Parser};
 use pest_derive::Parser;
 use thiserror::Error;
 
+pub use borrowed::{CookiePairRef, SetCookieRef};
+pub use builder::{CookieBuildError, SetCookieBuilder};
+pub use date::parse_cookie_date;
+pub use domain::CookieDomain;
+pub use error::SyntaxError;
+pub use fast::{parse_cookie_string_fast, parse_set_cookie_fast};
+pub use header::{CookieHeader, DuplicatePolicy};
+pub use iter::{parse_cookie_string_iter, CookiePairIter};
+pub use jar::{ApiContext, CookieJar, CookieJarError, StoredCookie};
+pub use lenient::{parse_cookie_string_lenient, parse_set_cookie_lenient};
+pub use matching::{canonicalize_host, default_path, domain_match, path_match};
+#[cfg(feature = "persist")]
+pub use persist::{CookieJarFileError, JarFormat};
+#[cfg(feature = "psl")]
+pub use psl::{PublicSuffixCheck, PublicSuffixList};
+pub use recover::{parse_set_cookie_recovering, RecoveredSetCookie, SetCookieProblem};
+pub use serialize::ToHeaderString;
+pub use url::Url;
+
 #[derive(Parser)]
 #[grammar = "./grammar.pest"]
 struct Grammar;
 
 /// An error that can occur when parsing a cookie/set-cookie string.
 #[derive(Error, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CookieParseError {
-    #[error("Invalid cookie string syntax")]
-    ErrorCookieStringSyntax,
+    #[error("Invalid cookie string syntax: {0}")]
+    ErrorCookieStringSyntax(SyntaxError),
     #[error("Cookie string is empty")]
     ErrorCookieStringEmpty,
+    #[error("Invalid value '{value}' for attribute '{name}'")]
+    ErrorInvalidAttributeValue { name: String, value: String },
+    #[error("Cookie '{name}' appears more than once")]
+    ErrorDuplicateCookieName { name: String },
 }
 
 // A structure that represents the cookie pair, which is the name and value of a cookie.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "crate::serde_impls::CookiePairFields")
+)]
 pub struct CookiePair {
     pub name: String,
+    /// The value without the surrounding DQUOTEs, if it was quoted.
     pub value: String,
+    /// Whether the value was wrapped in DQUOTEs.
+    pub quoted: bool,
+}
+
+impl CookiePair {
+    /// The value as it was written in the header, including the DQUOTEs if it was quoted.
+    pub fn raw_value(&self) -> String {
+        if self.quoted {
+            format!("\"{}\"", self.value)
+        } else {
+            self.value.clone()
+        }
+    }
+}
+
+/// The value of the `SameSite` attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+    /// A value that is not one of the known enforcement modes, kept as it was written.
+    Other(String),
+}
+
+impl SameSite {
+    /// Interpret a `SameSite` attribute value. Known values are matched case-insensitively.
+    pub fn parse(input: &str) -> SameSite {
+        if input.eq_ignore_ascii_case("Strict") {
+            SameSite::Strict
+        } else if input.eq_ignore_ascii_case("Lax") {
+            SameSite::Lax
+        } else if input.eq_ignore_ascii_case("None") {
+            SameSite::None
+        } else {
+            SameSite::Other(String::from(input))
+        }
+    }
+
+    /// The canonical spelling of the value, as it would appear in a header.
+    pub fn as_str(&self) -> &str {
+        match self {
+            SameSite::Strict => "Strict",
+            SameSite::Lax => "Lax",
+            SameSite::None => "None",
+            SameSite::Other(value) => value,
+        }
+    }
+}
+
+/// The value of the `Priority` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    /// Interpret a `Priority` attribute value, matched case-insensitively.
+    pub fn parse(input: &str) -> Option<Priority> {
+        if input.eq_ignore_ascii_case("Low") {
+            Some(Priority::Low)
+        } else if input.eq_ignore_ascii_case("Medium") {
+            Some(Priority::Medium)
+        } else if input.eq_ignore_ascii_case("High") {
+            Some(Priority::High)
+        } else {
+            None
+        }
+    }
+
+    /// The canonical spelling of the value, as it would appear in a header.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Priority::Low => "Low",
+            Priority::Medium => "Medium",
+            Priority::High => "High",
+        }
+    }
 }
 
 /// A structure that represents a set-cookie header contents and its attributes
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "crate::serde_impls::SetCookieFields")
+)]
 pub struct SetCookie {
     pub pair: CookiePair,
     pub secure: bool,
     pub http_only: bool,
-    pub max_age: Option<String>,
-    pub domain: Option<String>,
-    pub expires: Option<String>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::max_age"))]
+    pub max_age: Option<Duration>,
+    pub domain: Option<CookieDomain>,
+    pub expires: Option<DateTime<Utc>>,
     pub path: Option<String>,
+    pub same_site: Option<SameSite>,
+    pub partitioned: bool,
+    pub priority: Option<Priority>,
     pub extensions: Vec<String>,
 }
 
+impl SetCookie {
+    // A set-cookie structure without any attributes.
+    pub(crate) fn from_pair(pair: CookiePair) -> SetCookie {
+        SetCookie {
+            pair,
+            http_only: false,
+            secure: false,
+            domain: None,
+            max_age: None,
+            path: None,
+            expires: None,
+            same_site: None,
+            partitioned: false,
+            priority: None,
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Compute the moment this cookie expires, if it is not a session cookie.
+    ///
+    /// `Max-Age` takes precedence over `Expires`. Following RFC 6265 §5.2.2, a non-positive
+    /// `Max-Age` means the cookie expires immediately, which is reported as the earliest
+    /// representable time. Times past the representable range saturate.
+    pub fn expiry_time(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.max_age {
+            Some(max_age) if max_age <= Duration::zero() => Some(DateTime::<Utc>::MIN_UTC),
+            Some(max_age) => Some(
+                now.checked_add_signed(max_age)
+                    .unwrap_or(DateTime::<Utc>::MAX_UTC),
+            ),
+            None => self.expires,
+        }
+    }
+}
+
 /// Parse a cookie string into a vector of cookie pairs.
 pub fn parse_cookie_string(input: &str) -> Result<Vec<CookiePair>, CookieParseError> {
+    let cookie_pairs = parse_cookie_string_borrowed(input)?;
+
+    Ok(cookie_pairs
+        .into_iter()
+        .map(CookiePairRef::into_owned)
+        .collect())
+}
+
+/// Parse a set-cookie string into a set-cookie structure.
+pub fn parse_set_cookie(input: &str) -> Result<SetCookie, CookieParseError> {
+    parse_set_cookie_borrowed(input).map(SetCookieRef::into_owned)
+}
+
+/// Parse a cookie string into a vector of cookie pairs that borrow from the input.
+pub fn parse_cookie_string_borrowed(
+    input: &str,
+) -> Result<Vec<CookiePairRef<'_>>, CookieParseError> {
     let cookie_string = Grammar::parse(Rule::cookie_string, input)
-        .map_err(|_| CookieParseError::ErrorCookieStringSyntax)?
+        .map_err(|err| syntax_error(input, err))?
         .next()
         .ok_or(CookieParseError::ErrorCookieStringEmpty)?;
 
-    let cookie_pairs: Result<Vec<CookiePair>, _> = cookie_string
+    cookie_string
         .into_inner()
+        .filter(|pair| pair.as_rule() != Rule::EOI)
         .map(|pair| parse_cookie_pair(&pair))
-        .collect();
-
-    cookie_pairs.map_err(|_| CookieParseError::ErrorCookieStringSyntax)
+        .collect()
 }
 
-/// Parse a set-cookie string into a set-cookie structure.
-pub fn parse_set_cookie(input: &str) -> Result<SetCookie, CookieParseError> {
+/// Parse a set-cookie string into a set-cookie structure that borrows from the input.
+pub fn parse_set_cookie_borrowed(input: &str) -> Result<SetCookieRef<'_>, CookieParseError> {
     let set_cookie_string = Grammar::parse(Rule::set_cookie_string, input)
-        .map_err(|_| CookieParseError::ErrorCookieStringSyntax)?
+        .map_err(|err| syntax_error(input, err))?
         .next()
         .ok_or(CookieParseError::ErrorCookieStringEmpty)?;
 
-    let mut set_cookie_iter = set_cookie_string.into_inner();
+    let mut set_cookie_iter = set_cookie_string
+        .clone()
+        .into_inner()
+        .filter(|pair| pair.as_rule() != Rule::EOI);
 
     let cookie_pair_pair = set_cookie_iter
         .next()
-        .ok_or(CookieParseError::ErrorCookieStringSyntax)?;
+        .ok_or_else(|| unexpected_structure(&set_cookie_string, Rule::set_cookie_pair))?;
     let cookie_pair = parse_cookie_pair(&cookie_pair_pair)?;
 
-    let mut set_cookie = SetCookie {
-        pair: cookie_pair,
-        http_only: false,
-        secure: false,
-        domain: None,
-        max_age: None,
-        path: None,
-        expires: None,
-        extensions: Vec::new(),
-    };
+    let mut set_cookie = SetCookieRef::from_pair(cookie_pair);
 
     for cookie_attribute in set_cookie_iter {
-        if cookie_attribute.as_rule() != Rule::cookie_attribute {
-            return Err(CookieParseError::ErrorCookieStringSyntax);
-        }
-
-        let inner_attribute = cookie_attribute
-            .into_inner()
-            .next()
-            .ok_or(CookieParseError::ErrorCookieStringSyntax)?;
-
-        match inner_attribute.as_rule() {
-            Rule::cookie_httponly_attribute => set_cookie.http_only = true,
-            Rule::cookie_secure_attribute => set_cookie.secure = true,
-            Rule::cookie_domain_attribute => {
-                let value = inner_attribute
-                    .into_inner()
-                    .next()
-                    .ok_or(CookieParseError::ErrorCookieStringSyntax)?;
-
-                if value.as_rule() != Rule::cookie_domain_attribute_value {
-                    return Err(CookieParseError::ErrorCookieStringSyntax);
-                }
+        apply_attribute(&mut set_cookie, cookie_attribute)?;
+    }
 
-                set_cookie.domain = Some(String::from(value.as_str()));
-            }
-            Rule::cookie_max_age_attribute => {
-                let value = inner_attribute
-                    .into_inner()
-                    .next()
-                    .ok_or(CookieParseError::ErrorCookieStringSyntax)?;
-
-                if value.as_rule() != Rule::cookie_max_age_attribute_value {
-                    return Err(CookieParseError::ErrorCookieStringSyntax);
-                }
+    Ok(set_cookie)
+}
 
-                set_cookie.max_age = Some(String::from(value.as_str()));
-            }
-            Rule::cookie_path_attribute => {
-                let value = inner_attribute
-                    .into_inner()
-                    .next()
-                    .ok_or(CookieParseError::ErrorCookieStringSyntax)?;
-
-                if value.as_rule() != Rule::cookie_path_attribute_value {
-                    return Err(CookieParseError::ErrorCookieStringSyntax);
-                }
+// Record a `cookie_attribute` in the set-cookie structure. Later attributes override earlier ones.
+fn apply_attribute<'i>(
+    set_cookie: &mut SetCookieRef<'i>,
+    cookie_attribute: Pair<'i, Rule>,
+) -> Result<(), CookieParseError> {
+    if cookie_attribute.as_rule() != Rule::cookie_attribute {
+        return Err(unexpected_structure(
+            &cookie_attribute,
+            Rule::cookie_attribute,
+        ));
+    }
 
-                set_cookie.path = Some(String::from(value.as_str()));
-            }
-            Rule::cookie_expires_attribute => {
-                let value = inner_attribute
-                    .into_inner()
-                    .next()
-                    .ok_or(CookieParseError::ErrorCookieStringSyntax)?;
-
-                if value.as_rule() != Rule::cookie_expires_attribute_value {
-                    return Err(CookieParseError::ErrorCookieStringSyntax);
+    let inner_attribute = cookie_attribute
+        .clone()
+        .into_inner()
+        .next()
+        .ok_or_else(|| unexpected_structure(&cookie_attribute, Rule::cookie_attribute))?;
+
+    match inner_attribute.as_rule() {
+        Rule::cookie_httponly_attribute => set_cookie.http_only = true,
+        Rule::cookie_secure_attribute => set_cookie.secure = true,
+        Rule::cookie_domain_attribute => {
+            let (name, value) =
+                parse_attribute(inner_attribute, Rule::cookie_domain_attribute_value)?;
+
+            let domain = CookieDomain::parse(value).ok_or_else(|| {
+                CookieParseError::ErrorInvalidAttributeValue {
+                    name: String::from(name),
+                    value: String::from(value),
                 }
+            })?;
 
-                set_cookie.expires = Some(String::from(value.as_str()));
-            }
-            Rule::cookie_extension_attribute => {
-                set_cookie
-                    .extensions
-                    .push(String::from(inner_attribute.as_str()));
+            set_cookie.domain = Some(domain);
+        }
+        Rule::cookie_max_age_attribute => {
+            let (_, value) =
+                parse_attribute(inner_attribute, Rule::cookie_max_age_attribute_value)?;
+
+            set_cookie.max_age = Some(parse_max_age(value));
+        }
+        Rule::cookie_path_attribute => {
+            let (_, value) = parse_attribute(inner_attribute, Rule::cookie_path_attribute_value)?;
+
+            set_cookie.path = Some(value);
+        }
+        Rule::cookie_expires_attribute => {
+            let (_, value) =
+                parse_attribute(inner_attribute, Rule::cookie_expires_attribute_value)?;
+
+            // An Expires that is not a cookie-date is ignored:
+            // https://datatracker.ietf.org/doc/html/rfc6265#section-5.2.1
+            if let Some(expires) = parse_cookie_date(value) {
+                set_cookie.expires = Some(expires);
             }
-            _ => return Err(CookieParseError::ErrorCookieStringSyntax),
+        }
+        Rule::cookie_samesite_attribute => {
+            let (_, value) =
+                parse_attribute(inner_attribute, Rule::cookie_samesite_attribute_value)?;
+
+            set_cookie.same_site = Some(SameSite::parse(value));
+        }
+        Rule::cookie_partitioned_attribute => set_cookie.partitioned = true,
+        Rule::cookie_priority_attribute => {
+            let (_, value) =
+                parse_attribute(inner_attribute, Rule::cookie_priority_attribute_value)?;
+
+            set_cookie.priority = Priority::parse(value);
+        }
+        Rule::cookie_extension_attribute => {
+            set_cookie.extensions.push(inner_attribute.as_str());
+        }
+        _ => {
+            return Err(unexpected_structure(
+                &inner_attribute,
+                Rule::cookie_attribute,
+            ))
         }
     }
 
-    Ok(set_cookie)
+    Ok(())
 }
 
-fn parse_cookie_pair(input: &Pair<Rule>) -> Result<CookiePair, CookieParseError> {
-    if input.as_rule() != Rule::cookie_pair {
-        return Err(CookieParseError::ErrorCookieStringSyntax);
+// Split a `<name>=<value>` attribute into the name as it was spelled and the value.
+fn parse_attribute<'i>(
+    attribute: Pair<'i, Rule>,
+    value_rule: Rule,
+) -> Result<(&'i str, &'i str), CookieParseError> {
+    let mut iter = attribute.clone().into_inner();
+
+    let name = iter
+        .next()
+        .ok_or_else(|| unexpected_structure(&attribute, attribute.as_rule()))?;
+
+    let value = iter
+        .next()
+        .ok_or_else(|| unexpected_structure(&attribute, value_rule))?;
+
+    if value.as_rule() != value_rule {
+        return Err(unexpected_structure(&value, value_rule));
+    }
+
+    Ok((name.as_str(), value.as_str()))
+}
+
+fn parse_cookie_pair<'i>(input: &Pair<'i, Rule>) -> Result<CookiePairRef<'i>, CookieParseError> {
+    if input.as_rule() != Rule::cookie_pair && input.as_rule() != Rule::set_cookie_pair {
+        return Err(unexpected_structure(input, Rule::cookie_pair));
     }
 
     let mut iter = input.clone().into_inner();
 
     let name = iter
         .next()
-        .ok_or(CookieParseError::ErrorCookieStringSyntax)?;
+        .ok_or_else(|| unexpected_structure(input, Rule::cookie_name))?;
 
     if name.as_rule() != Rule::cookie_name {
-        return Err(CookieParseError::ErrorCookieStringSyntax);
+        return Err(unexpected_structure(&name, Rule::cookie_name));
     }
 
-    let value = iter
-        .next()
-        .ok_or(CookieParseError::ErrorCookieStringSyntax)?;
+    let value = match iter.next() {
+        Some(value) => value,
+        None => {
+            return Ok(CookiePairRef {
+                name: name.as_str(),
+                value: "",
+                quoted: false,
+            })
+        }
+    };
 
     if value.as_rule() != Rule::cookie_value {
-        return Err(CookieParseError::ErrorCookieStringSyntax);
+        return Err(unexpected_structure(&value, Rule::cookie_value));
     }
 
-    return Ok(CookiePair {
-        name: String::from(name.as_str()),
-        value: String::from(value.as_str()),
+    let quoted_value = value
+        .clone()
+        .into_inner()
+        .find(|inner| inner.as_rule() == Rule::cookie_quoted_value);
+
+    Ok(CookiePairRef {
+        name: name.as_str(),
+        value: quoted_value.as_ref().unwrap_or(&value).as_str(),
+        quoted: quoted_value.is_some(),
+    })
+}
+
+fn syntax_error(input: &str, err: pest::error::Error<Rule>) -> CookieParseError {
+    CookieParseError::ErrorCookieStringSyntax(SyntaxError::from_pest(input, 0, err))
+}
+
+fn unexpected_structure(pair: &Pair<Rule>, expected: Rule) -> CookieParseError {
+    CookieParseError::ErrorCookieStringSyntax(SyntaxError::unexpected_structure(pair, expected))
+}
+
+// delta-seconds: https://datatracker.ietf.org/doc/html/rfc6265#section-5.2.2
+// Values that do not fit into a `Duration` saturate instead of overflowing.
+fn parse_max_age(input: &str) -> Duration {
+    let (negative, digits) = match input.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, input),
+    };
+
+    let seconds = digits.bytes().fold(0i64, |seconds, digit| {
+        seconds
+            .saturating_mul(10)
+            .saturating_add(i64::from(digit - b'0'))
     });
+
+    max_age_from_seconds(if negative { -seconds } else { seconds })
+}
+
+// Seconds that do not fit into a `Duration` saturate to `Duration::MIN`/`Duration::MAX`.
+fn max_age_from_seconds(seconds: i64) -> Duration {
+    Duration::try_seconds(seconds).unwrap_or(if seconds < 0 {
+        Duration::MIN
+    } else {
+        Duration::MAX
+    })
+}
+
+// The inverse of `max_age_from_seconds`: saturated values map to seconds out of range, so they
+// saturate again when read back.
+fn max_age_to_seconds(max_age: Duration) -> i64 {
+    if max_age == Duration::MAX {
+        i64::MAX
+    } else if max_age == Duration::MIN {
+        i64::MIN
+    } else {
+        max_age.num_seconds()
+    }
 }
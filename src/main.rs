@@ -1,7 +1,11 @@
-use std::{fs, process};
+use std::{
+    env, fs,
+    io::{self, IsTerminal},
+    process,
+};
 
 use clap::{Parser, Subcommand};
-use cookie_parser::{parse_cookie_string, parse_set_cookie};
+use cookie_parser::{parse_cookie_string, parse_set_cookie, CookieParseError, SyntaxError};
 
 #[derive(Parser)]
 #[command(
@@ -47,7 +51,7 @@ fn main() {
                     }
                 }
                 Err(err) => {
-                    eprintln!("Error parsing: {}", err);
+                    report_parse_error(&file_contents, &err);
                     process::exit(1)
                 }
             }
@@ -66,9 +70,10 @@ fn main() {
                     println!("Cookie: {}={}", result.pair.name, result.pair.value);
                     println!("Secure: {}", result.secure);
                     println!("HttpOnly: {}", result.http_only);
+                    println!("Partitioned: {}", result.partitioned);
                     
                     if let Some(max_age) = &result.max_age {
-                        println!("Max-Age: {}", max_age);
+                        println!("Max-Age: {}", max_age.num_seconds());
                     }
                     
                     if let Some(domain) = &result.domain {
@@ -83,6 +88,14 @@ fn main() {
                         println!("Path: {}", path);
                     }
                     
+                    if let Some(same_site) = &result.same_site {
+                        println!("SameSite: {}", same_site.as_str());
+                    }
+
+                    if let Some(priority) = &result.priority {
+                        println!("Priority: {}", priority.as_str());
+                    }
+
                     if !result.extensions.is_empty() {
                         println!("Extensions:");
                         for ext in &result.extensions {
@@ -91,7 +104,7 @@ fn main() {
                     }
                 }
                 Err(err) => {
-                    eprintln!("Error parsing: {}", err);
+                    report_parse_error(&file_contents, &err);
                     process::exit(1)
                 }
             }
@@ -123,3 +136,231 @@ fn main() {
         }
     }
 }
+
+// ANSI styling for diagnostics, disabled when stderr is not a terminal or NO_COLOR is set.
+struct Style {
+    enabled: bool,
+}
+
+impl Style {
+    fn detect() -> Style {
+        Style {
+            enabled: io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none(),
+        }
+    }
+
+    fn paint(&self, code: &str, text: &str) -> String {
+        if self.enabled {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        } else {
+            String::from(text)
+        }
+    }
+
+    fn error(&self, text: &str) -> String {
+        self.paint("1;31", text)
+    }
+
+    fn caret(&self, text: &str) -> String {
+        self.paint("1;31", text)
+    }
+
+    fn gutter(&self, text: &str) -> String {
+        self.paint("1;34", text)
+    }
+
+    fn help(&self, text: &str) -> String {
+        self.paint("1;32", text)
+    }
+}
+
+fn report_parse_error(input: &str, err: &CookieParseError) {
+    eprint!("{}", render_parse_error(&Style::detect(), input, err));
+}
+
+fn render_parse_error(style: &Style, input: &str, err: &CookieParseError) -> String {
+    let mut output = format!("{} {}\n", style.error("error:"), err);
+
+    match err {
+        CookieParseError::ErrorCookieStringSyntax(error) => {
+            output += &render_caret(style, input, error.line, error.column);
+
+            if let Some(explanation) = explain_rules(&error.rules) {
+                output += &format!("{} {}\n", style.gutter("  = rule:"), explanation);
+            }
+
+            if let Some(suggestion) = suggest_syntax_fix(input, error) {
+                output += &format!("{} {}\n", style.help("  = help:"), suggestion);
+            }
+        }
+        CookieParseError::ErrorInvalidAttributeValue { name, value } => {
+            if let Some(offset) = find_attribute_value(input, name, value) {
+                let before = &input[..offset];
+                let line = before.matches('\n').count() + 1;
+                let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+                let column = before[line_start..].chars().count() + 1;
+
+                output += &render_caret(style, input, line, column);
+            }
+
+            output += &format!(
+                "{} the attribute matched the grammar, but its value could not be interpreted\n",
+                style.gutter("  = note:")
+            );
+            output += &format!("{} {}\n", style.help("  = help:"), suggest_attribute_fix(name));
+        }
+        CookieParseError::ErrorDuplicateCookieName { .. } => {
+            output += &format!(
+                "{} remove or rename all but one of the cookies with this name\n",
+                style.help("  = help:")
+            );
+        }
+        CookieParseError::ErrorCookieStringEmpty => {
+            output += &format!(
+                "{} the file should contain a header value such as 'name=value'\n",
+                style.help("  = help:")
+            );
+        }
+    }
+
+    output
+}
+
+// Render the offending line with a caret under the given 1-based column.
+fn render_caret(style: &Style, input: &str, line: usize, column: usize) -> String {
+    let text = input.lines().nth(line - 1).unwrap_or("");
+    let number = line.to_string();
+    let padding = " ".repeat(number.len());
+
+    // Keep tabs so that the caret lines up with the text above it.
+    let indent: String = text
+        .chars()
+        .take(column - 1)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    format!(
+        "{} {}\n{} {} {}\n{} {} {}{}\n",
+        padding,
+        style.gutter("|"),
+        style.gutter(&number),
+        style.gutter("|"),
+        text,
+        padding,
+        style.gutter("|"),
+        indent,
+        style.caret("^")
+    )
+}
+
+// The rule to explain when several failed at the same offset. An optional part such as an empty
+// cookie value lets EOI fail there too, but the part is the more helpful one to explain.
+fn primary_rule(rules: &[String]) -> Option<&str> {
+    rules
+        .iter()
+        .find(|rule| *rule != "EOI")
+        .or(rules.first())
+        .map(String::as_str)
+}
+
+// Explain the grammar.pest rules that failed in terms of what they accept.
+fn explain_rules(rules: &[String]) -> Option<String> {
+    let rule = primary_rule(rules)?;
+
+    let explanation = match rule {
+        "EOI" => "cookie_string and set_cookie_string separate entries with exactly \"; \" (a semicolon and one space) and allow nothing after the last one",
+        "cookie_pair" => "cookie_pair is a cookie_name followed by '=' and a cookie_value",
+        "cookie_name" => "cookie_name is a token: one or more characters other than ( ) < > @ , ; : \\ \" / [ ] ? = { }",
+        "cookie_value" | "cookie_quoted_value" => "cookie_value is one or more cookie-octets (printable US-ASCII except whitespace, DQUOTE, comma, semicolon and backslash), optionally wrapped in DQUOTEs",
+        "cookie_attribute" => "cookie_attribute is one of Expires, Max-Age, Domain, Path, Secure, HttpOnly, SameSite, Partitioned, Priority or an extension",
+        "cookie_max_age_attribute_value" => "cookie_max_age_attribute_value is an optional '-' followed by digits",
+        "cookie_domain_attribute_value" => "cookie_domain_attribute_value is a host name made of letters, digits and hyphens separated by dots, or a bracketed IPv6 address",
+        "cookie_expires_attribute_value" | "cookie_path_attribute_value" | "cookie_samesite_attribute_value" | "cookie_priority_attribute_value" => {
+            return Some(format!("{} is any non-empty text up to the next ';'", rule));
+        }
+        _ => return Some(format!("the {} rule in grammar.pest did not match", rule)),
+    };
+
+    Some(String::from(explanation))
+}
+
+fn suggest_syntax_fix(input: &str, error: &SyntaxError) -> Option<String> {
+    let rest = &input[error.offset..];
+    let next = rest.chars().next();
+
+    let suggestion = match primary_rule(&error.rules) {
+        Some("EOI") => match next {
+            Some(';') if rest[1..].trim().is_empty() => "remove the trailing ';'",
+            Some(';') => "add a space after ';'",
+            Some(c) if c.is_whitespace() && rest.trim_start().starts_with(';') => {
+                "remove the whitespace before ';'"
+            }
+            Some('\r' | '\n') => "remove the line break at the end of the input",
+            Some(_) => "percent-encode this character, or separate entries with \"; \"",
+            None => return None,
+        },
+        Some("cookie_value") | Some("cookie_quoted_value") => match next {
+            None | Some(';') => "add a value after '='",
+            Some('"') => "close the quoted value with '\"'",
+            Some(_) => "percent-encode characters that are not allowed in cookie values",
+        },
+        Some("cookie_name") => "give the cookie a non-empty name without separators",
+        Some("cookie_pair") => "write the cookie as name=value",
+        Some("cookie_attribute") if rest.trim().is_empty() => "remove the trailing \"; \"",
+        Some("cookie_attribute") => "remove the extra whitespace before the attribute",
+        Some("cookie_max_age_attribute_value") => {
+            "use a whole number of seconds, e.g. Max-Age=3600"
+        }
+        Some("cookie_domain_attribute_value") => {
+            "use a host name such as example.com or an IP address such as 127.0.0.1"
+        }
+        Some("cookie_priority_attribute_value") => "add a priority after '=', e.g. Priority=High",
+        Some("cookie_path_attribute_value") => "add a path after '=', e.g. Path=/",
+        Some("cookie_expires_attribute_value") => {
+            "add a date after '=', e.g. Expires=Wed, 21 Oct 2015 07:28:00 GMT"
+        }
+        Some("cookie_samesite_attribute_value") => {
+            "use SameSite=Strict, SameSite=Lax or SameSite=None"
+        }
+        _ => return None,
+    };
+
+    Some(String::from(suggestion))
+}
+
+fn suggest_attribute_fix(name: &str) -> String {
+    let suggestion = if name.eq_ignore_ascii_case("Expires") {
+        "use an HTTP date such as 'Wed, 21 Oct 2015 07:28:00 GMT'"
+    } else if name.eq_ignore_ascii_case("Domain") {
+        "use a host name such as example.com or an IP address such as 127.0.0.1 or [::1]"
+    } else {
+        "check the value of this attribute"
+    };
+
+    String::from(suggestion)
+}
+
+// Locate the value of an attribute that was spelled `name` in the input. Only attributes are
+// searched, i.e. the text after each ';', since the name and value may also appear in the cookie
+// pair or in other attributes.
+fn find_attribute_value(input: &str, name: &str, value: &str) -> Option<usize> {
+    let mut offset = input.find(';')? + 1;
+
+    for attribute in input[offset..].split(';') {
+        let attribute_offset = offset;
+        offset += attribute.len() + 1;
+
+        let rest = attribute
+            .trim_start()
+            .strip_prefix(name)
+            .map(|rest| rest.trim_start_matches([' ', '\t']))
+            .and_then(|rest| rest.strip_prefix('='))
+            .map(|rest| rest.trim_start_matches([' ', '\t']));
+
+        if let Some(rest) = rest.filter(|rest| rest.starts_with(value)) {
+            return Some(attribute_offset + attribute.len() - rest.len());
+        }
+    }
+
+    None
+}
@@ -0,0 +1,107 @@
+// https://datatracker.ietf.org/doc/html/rfc6265#section-5.1
+
+use std::net::Ipv4Addr;
+
+use url::Host;
+
+use crate::{CookieDomain, SetCookie};
+
+/// Canonicalize a host name as RFC 6265 §5.1.2 requires: lowercase it and convert
+/// internationalized labels to their punycode form.
+///
+/// IPv4 addresses are normalized and IPv6 addresses are returned in brackets. Returns `None`
+/// when the input is not a valid host.
+pub fn canonicalize_host(host: &str) -> Option<String> {
+    Host::parse(host).ok().map(|host| host.to_string())
+}
+
+/// Whether `host` domain-matches `domain` (RFC 6265 §5.1.3).
+///
+/// Both are canonicalized first, so `WWW.Example.com` matches `example.com`. The domain is
+/// compared as is, so a leading dot from a `Domain` attribute must already be removed, as
+/// [`CookieDomain`] does. IP addresses only match themselves. Returns `false` when either
+/// argument is not a valid host.
+pub fn domain_match(host: &str, domain: &str) -> bool {
+    match (canonicalize_host(host), canonicalize_host(domain)) {
+        (Some(host), Some(domain)) => is_domain_match(&host, &domain),
+        _ => false,
+    }
+}
+
+/// Whether `request_path` path-matches `cookie_path` (RFC 6265 §5.1.4).
+///
+/// The cookie path must be equal to the request path or a prefix of it that ends at a `/`, so
+/// `/docs` matches `/docs` and `/docs/web` but not `/docsearch`. Paths are case-sensitive.
+pub fn path_match(request_path: &str, cookie_path: &str) -> bool {
+    match request_path.strip_prefix(cookie_path) {
+        Some(rest) => rest.is_empty() || cookie_path.ends_with('/') || rest.starts_with('/'),
+        None => false,
+    }
+}
+
+/// The default-path of a cookie set by a request to `uri_path` (RFC 6265 §5.1.4), used when
+/// the cookie has no valid `Path` attribute.
+///
+/// This is the path up to, but not including, its last `/`, or `/` when that would be empty or
+/// the path does not start with `/`. Pass the path without the query, e.g. from `Url::path`.
+pub fn default_path(uri_path: &str) -> &str {
+    if !uri_path.starts_with('/') {
+        return "/";
+    }
+
+    match uri_path.rfind('/') {
+        Some(0) | None => "/",
+        Some(index) => &uri_path[..index],
+    }
+}
+
+impl SetCookie {
+    /// Whether `host` domain-matches the `Domain` attribute. See [`domain_match`].
+    ///
+    /// Without a `Domain` attribute the cookie belongs only to the host that set it, which the
+    /// set-cookie structure does not record, so `false` is returned.
+    pub fn domain_matches(&self, host: &str) -> bool {
+        let domain = self.domain.as_ref().and_then(canonicalize_domain);
+
+        match (canonicalize_host(host), domain) {
+            (Some(host), Some(domain)) => is_domain_match(&host, &domain),
+            _ => false,
+        }
+    }
+
+    /// Whether `request_path` path-matches the `Path` attribute. See [`path_match`].
+    ///
+    /// Without a `Path` attribute starting with `/` the cookie path is the default-path of the
+    /// request that set it, which the set-cookie structure does not record, so `false` is
+    /// returned.
+    pub fn path_matches(&self, request_path: &str) -> bool {
+        match &self.path {
+            Some(path) if path.starts_with('/') => path_match(request_path, path),
+            _ => false,
+        }
+    }
+}
+
+// Both arguments must be canonicalized.
+pub(crate) fn is_domain_match(host: &str, domain: &str) -> bool {
+    if host == domain {
+        return true;
+    }
+
+    host.strip_suffix(domain)
+        .is_some_and(|prefix| prefix.ends_with('.'))
+        && !is_ip_address(host)
+}
+
+// Domain attribute values are lowercased by the parser, but internationalized labels still
+// have to be converted to punycode to compare them with a canonicalized host.
+pub(crate) fn canonicalize_domain(domain: &CookieDomain) -> Option<String> {
+    match domain {
+        CookieDomain::Host(host) => canonicalize_host(host),
+        CookieDomain::Ip(_) => Some(domain.to_string()),
+    }
+}
+
+pub(crate) fn is_ip_address(host: &str) -> bool {
+    host.starts_with('[') || host.parse::<Ipv4Addr>().is_ok()
+}
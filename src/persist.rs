@@ -0,0 +1,332 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process,
+};
+
+use chrono::{DateTime, Utc};
+use fs4::fs_std::FileExt;
+use thiserror::Error;
+
+use crate::{CookieJar, CookiePair, SameSite, StoredCookie};
+
+const BINARY_MAGIC: &[u8; 4] = b"CKJR";
+const BINARY_VERSION: u8 = 1;
+
+const FLAG_QUOTED: u8 = 1;
+const FLAG_HOST_ONLY: u8 = 1 << 1;
+const FLAG_SECURE_ONLY: u8 = 1 << 2;
+const FLAG_HTTP_ONLY: u8 = 1 << 3;
+const FLAG_SAME_SITE: u8 = 1 << 4;
+
+/// The file format of a saved [`CookieJar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JarFormat {
+    /// The jar's serde representation as JSON.
+    Json,
+    /// A compact, versioned binary encoding.
+    Binary,
+}
+
+/// An error that can occur when saving or loading a cookie jar.
+#[derive(Error, Debug)]
+pub enum CookieJarFileError {
+    #[error("I/O error: {0}")]
+    ErrorIo(#[from] io::Error),
+    #[error("Invalid JSON cookie jar: {0}")]
+    ErrorJson(#[from] serde_json::Error),
+    #[error("Invalid binary cookie jar: {0}")]
+    ErrorBinary(String),
+}
+
+impl CookieJar {
+    /// Encode the cookies that outlive the session, leaving out session cookies and the ones
+    /// expired at `now`.
+    pub fn to_bytes(&self, format: JarFormat, now: DateTime<Utc>) -> Vec<u8> {
+        let cookies: Vec<StoredCookie> = self
+            .iter()
+            .filter(|cookie| cookie.is_persistent() && !cookie.is_expired(now))
+            .cloned()
+            .collect();
+
+        match format {
+            JarFormat::Json => serde_json::to_vec(&CookieJar::from_cookies(cookies))
+                .expect("a cookie jar always serializes to JSON"),
+            JarFormat::Binary => encode_binary(&cookies),
+        }
+    }
+
+    /// Decode a jar written by [`to_bytes`](Self::to_bytes), leaving out the cookies that are
+    /// session cookies or expired at `now`.
+    pub fn from_bytes(
+        bytes: &[u8],
+        format: JarFormat,
+        now: DateTime<Utc>,
+    ) -> Result<CookieJar, CookieJarFileError> {
+        let cookies = match format {
+            JarFormat::Json => serde_json::from_slice::<CookieJar>(bytes)?.into_cookies(),
+            JarFormat::Binary => decode_binary(bytes)?,
+        };
+
+        Ok(CookieJar::from_cookies(
+            cookies
+                .into_iter()
+                .filter(|cookie| cookie.is_persistent() && !cookie.is_expired(now))
+                .collect(),
+        ))
+    }
+
+    /// Save the jar to `path`, as [`to_bytes`](Self::to_bytes) encodes it.
+    ///
+    /// The file is replaced atomically by writing a temporary file next to it and renaming it,
+    /// while holding an exclusive lock on `<path>.lock`, so readers never see a partial jar.
+    pub fn save(
+        &self,
+        path: impl AsRef<Path>,
+        format: JarFormat,
+        now: DateTime<Utc>,
+    ) -> Result<(), CookieJarFileError> {
+        let path = path.as_ref();
+        let lock = open_lock_file(path)?;
+        FileExt::lock_exclusive(&lock)?;
+
+        write_atomically(path, &self.to_bytes(format, now))
+    }
+
+    /// Load a jar saved with [`save`](Self::save), holding a shared lock on `<path>.lock`
+    /// while reading. A missing file gives an empty jar.
+    pub fn load(
+        path: impl AsRef<Path>,
+        format: JarFormat,
+        now: DateTime<Utc>,
+    ) -> Result<CookieJar, CookieJarFileError> {
+        let path = path.as_ref();
+        let lock = open_lock_file(path)?;
+        FileExt::lock_shared(&lock)?;
+
+        read_jar(path, format, now)
+    }
+
+    /// Load the jar at `path`, let `update` change it and save it again, holding an exclusive
+    /// lock on `<path>.lock` throughout.
+    ///
+    /// Use this instead of separate `load` and `save` calls when several processes share a
+    /// jar, so that none of them overwrites cookies stored by another in the meantime.
+    pub fn update<T>(
+        path: impl AsRef<Path>,
+        format: JarFormat,
+        now: DateTime<Utc>,
+        update: impl FnOnce(&mut CookieJar) -> T,
+    ) -> Result<T, CookieJarFileError> {
+        let path = path.as_ref();
+        let lock = open_lock_file(path)?;
+        FileExt::lock_exclusive(&lock)?;
+
+        let mut jar = read_jar(path, format, now)?;
+        let result = update(&mut jar);
+        write_atomically(path, &jar.to_bytes(format, now))?;
+
+        Ok(result)
+    }
+}
+
+fn read_jar(
+    path: &Path,
+    format: JarFormat,
+    now: DateTime<Utc>,
+) -> Result<CookieJar, CookieJarFileError> {
+    match fs::read(path) {
+        Ok(bytes) => CookieJar::from_bytes(&bytes, format, now),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(CookieJar::new()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+// The jar file itself is replaced on every save, so locks are taken on a separate file that
+// stays in place. They are released when the file is closed. The trait methods are called
+// explicitly, as newer standard libraries have inherent `File` methods with the same names.
+fn open_lock_file(path: &Path) -> io::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(sibling_path(path, ".lock"))
+}
+
+fn write_atomically(path: &Path, bytes: &[u8]) -> Result<(), CookieJarFileError> {
+    let temporary_path = sibling_path(path, &format!(".{}.tmp", process::id()));
+
+    let result = File::create(&temporary_path).and_then(|mut file| {
+        file.write_all(bytes)?;
+        file.sync_all()
+    });
+
+    if let Err(error) = result.and_then(|_| fs::rename(&temporary_path, path)) {
+        let _ = fs::remove_file(&temporary_path);
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut file_name = path.as_os_str().to_owned();
+    file_name.push(suffix);
+    PathBuf::from(file_name)
+}
+
+// Binary layout, all integers little-endian:
+//
+//   magic "CKJR", version (u8), cookie count (u32), then for every cookie:
+//   flags (u8), name, value, domain, path, [same-site,] expiry, creation and last-access time
+//
+// Strings are a u32 byte length followed by UTF-8 bytes, times are i64 seconds and u32
+// nanoseconds since the Unix epoch. The same-site value is only present with FLAG_SAME_SITE.
+fn encode_binary(cookies: &[StoredCookie]) -> Vec<u8> {
+    let mut output = Vec::new();
+
+    output.extend_from_slice(BINARY_MAGIC);
+    output.push(BINARY_VERSION);
+    output.extend_from_slice(&(cookies.len() as u32).to_le_bytes());
+
+    for cookie in cookies {
+        let flags = [
+            (cookie.pair.quoted, FLAG_QUOTED),
+            (cookie.host_only, FLAG_HOST_ONLY),
+            (cookie.secure_only, FLAG_SECURE_ONLY),
+            (cookie.http_only, FLAG_HTTP_ONLY),
+            (cookie.same_site.is_some(), FLAG_SAME_SITE),
+        ]
+        .into_iter()
+        .filter(|(is_set, _)| *is_set)
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+        output.push(flags);
+        encode_str(&mut output, &cookie.pair.name);
+        encode_str(&mut output, &cookie.pair.value);
+        encode_str(&mut output, &cookie.domain);
+        encode_str(&mut output, &cookie.path);
+
+        if let Some(same_site) = &cookie.same_site {
+            encode_str(&mut output, same_site.as_str());
+        }
+
+        // Only persistent cookies are encoded.
+        encode_time(
+            &mut output,
+            cookie.expiry_time.unwrap_or(DateTime::<Utc>::MAX_UTC),
+        );
+        encode_time(&mut output, cookie.creation_time);
+        encode_time(&mut output, cookie.last_access_time);
+    }
+
+    output
+}
+
+fn encode_str(output: &mut Vec<u8>, value: &str) {
+    output.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    output.extend_from_slice(value.as_bytes());
+}
+
+fn encode_time(output: &mut Vec<u8>, time: DateTime<Utc>) {
+    output.extend_from_slice(&time.timestamp().to_le_bytes());
+    output.extend_from_slice(&time.timestamp_subsec_nanos().to_le_bytes());
+}
+
+fn decode_binary(input: &[u8]) -> Result<Vec<StoredCookie>, CookieJarFileError> {
+    let mut reader = BinaryReader { input };
+
+    if reader.take(BINARY_MAGIC.len())? != BINARY_MAGIC {
+        return Err(binary_error("not a cookie jar file"));
+    }
+
+    let version = reader.take(1)?[0];
+
+    if version != BINARY_VERSION {
+        return Err(binary_error(&format!("unsupported version {}", version)));
+    }
+
+    let count = reader.u32()?;
+    let mut cookies = Vec::new();
+
+    for _ in 0..count {
+        let flags = reader.take(1)?[0];
+        let name = reader.string()?;
+        let value = reader.string()?;
+        let domain = reader.string()?;
+        let path = reader.string()?;
+        let same_site = if flags & FLAG_SAME_SITE != 0 {
+            Some(SameSite::parse(&reader.string()?))
+        } else {
+            None
+        };
+
+        cookies.push(StoredCookie {
+            pair: CookiePair {
+                name,
+                value,
+                quoted: flags & FLAG_QUOTED != 0,
+            },
+            domain,
+            path,
+            expiry_time: Some(reader.time()?),
+            creation_time: reader.time()?,
+            last_access_time: reader.time()?,
+            host_only: flags & FLAG_HOST_ONLY != 0,
+            secure_only: flags & FLAG_SECURE_ONLY != 0,
+            http_only: flags & FLAG_HTTP_ONLY != 0,
+            same_site,
+        });
+    }
+
+    if !reader.input.is_empty() {
+        return Err(binary_error("trailing bytes after the last cookie"));
+    }
+
+    Ok(cookies)
+}
+
+struct BinaryReader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> BinaryReader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], CookieJarFileError> {
+        if self.input.len() < length {
+            return Err(binary_error("unexpected end of file"));
+        }
+
+        let (bytes, rest) = self.input.split_at(length);
+        self.input = rest;
+
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, CookieJarFileError> {
+        let bytes = self.take(4)?;
+
+        Ok(u32::from_le_bytes(
+            bytes.try_into().expect("4 bytes were taken"),
+        ))
+    }
+
+    fn string(&mut self) -> Result<String, CookieJarFileError> {
+        let length = self.u32()? as usize;
+
+        String::from_utf8(self.take(length)?.to_vec())
+            .map_err(|_| binary_error("string is not valid UTF-8"))
+    }
+
+    fn time(&mut self) -> Result<DateTime<Utc>, CookieJarFileError> {
+        let bytes = self.take(8)?;
+        let seconds = i64::from_le_bytes(bytes.try_into().expect("8 bytes were taken"));
+        let nanoseconds = self.u32()?;
+
+        DateTime::from_timestamp(seconds, nanoseconds).ok_or_else(|| binary_error("invalid time"))
+    }
+}
+
+fn binary_error(message: &str) -> CookieJarFileError {
+    CookieJarFileError::ErrorBinary(String::from(message))
+}
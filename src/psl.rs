@@ -0,0 +1,154 @@
+// https://github.com/publicsuffix/list/wiki/Format
+
+use std::{collections::HashSet, fs, io, path::Path};
+
+use crate::{
+    matching::{canonicalize_domain, canonicalize_host, is_ip_address},
+    SetCookie,
+};
+
+/// A Public Suffix List, the domains under which anyone can register names, such as `com`,
+/// `co.uk` or `github.io`.
+///
+/// User agents refuse cookies whose `Domain` attribute is a public suffix, since they would be
+/// sent to every site registered under it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PublicSuffixList {
+    rules: HashSet<String>,
+    // Wildcard rules (`*.ck`) without the leading `*.`.
+    wildcard_rules: HashSet<String>,
+    // Exception rules (`!www.ck`) without the leading `!`.
+    exception_rules: HashSet<String>,
+}
+
+/// The outcome of [`SetCookie::check_public_suffix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PublicSuffixCheck {
+    /// There is no `Domain` attribute or it is not a public suffix.
+    Allowed,
+    /// The `Domain` attribute is a public suffix equal to the request host, so the cookie is
+    /// kept as a host-only cookie for that host.
+    HostOnly,
+    /// The `Domain` attribute is a public suffix other than the request host. The cookie would
+    /// be a supercookie and must be ignored.
+    Rejected,
+}
+
+impl PublicSuffixList {
+    /// The full list as of 2023-02-09, bundled with the crate. Load a newer copy with
+    /// [`from_file`](Self::from_file) to pick up later additions.
+    pub fn bundled() -> PublicSuffixList {
+        // The upstream file of version 20230209.2326, kept byte-identical.
+        PublicSuffixList::parse(include_str!("public_suffix_list.dat"))
+    }
+
+    /// Read a list in the `public_suffix_list.dat` format from a file.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<PublicSuffixList> {
+        Ok(PublicSuffixList::parse(&fs::read_to_string(path)?))
+    }
+
+    /// Parse a list in the `public_suffix_list.dat` format.
+    ///
+    /// Each line holds one rule, and only its text up to the first whitespace is used. Comments
+    /// (`//`), blank lines and rules that are not valid host names are skipped. Internationalized
+    /// rules are converted to punycode.
+    pub fn parse(input: &str) -> PublicSuffixList {
+        let mut list = PublicSuffixList::default();
+
+        for line in input.lines() {
+            let Some(rule) = line.split_whitespace().next() else {
+                continue;
+            };
+
+            if rule.starts_with("//") {
+                continue;
+            }
+
+            let (rules, rule) = if let Some(rule) = rule.strip_prefix('!') {
+                (&mut list.exception_rules, rule)
+            } else if let Some(rule) = rule.strip_prefix("*.") {
+                (&mut list.wildcard_rules, rule)
+            } else {
+                (&mut list.rules, rule)
+            };
+
+            if let Some(rule) = canonicalize_host(rule) {
+                rules.insert(rule);
+            }
+        }
+
+        list
+    }
+
+    /// The public suffix of `domain`, found with the prevailing rule of the list. Without a
+    /// matching rule it is the last label, as if the list contained `*`.
+    ///
+    /// The domain is canonicalized first, so the result is lowercase punycode. Returns `None`
+    /// for invalid hosts and IP addresses, which have no public suffix.
+    pub fn public_suffix(&self, domain: &str) -> Option<String> {
+        let domain = canonicalize_host(domain)?;
+
+        if is_ip_address(&domain) {
+            return None;
+        }
+
+        // The suffixes of the domain, from the whole domain to its last label.
+        let mut suffixes = vec![domain.as_str()];
+        suffixes.extend(
+            domain
+                .match_indices('.')
+                .map(|(index, _)| &domain[index + 1..]),
+        );
+
+        // An exception rule prevails over every other rule, and its public suffix is the rule
+        // without its leftmost label.
+        for (index, suffix) in suffixes.iter().enumerate() {
+            if self.exception_rules.contains(*suffix) {
+                return suffixes.get(index + 1).map(|suffix| String::from(*suffix));
+            }
+        }
+
+        // Otherwise the rule with the most labels prevails. A wildcard rule matching a suffix
+        // has one more label than the suffix.
+        for (index, suffix) in suffixes.iter().enumerate() {
+            if index > 0 && self.wildcard_rules.contains(*suffix) {
+                return Some(String::from(suffixes[index - 1]));
+            }
+
+            if self.rules.contains(*suffix) {
+                return Some(String::from(*suffix));
+            }
+        }
+
+        suffixes.last().map(|suffix| String::from(*suffix))
+    }
+
+    /// Whether `domain` is itself a public suffix.
+    pub fn is_public_suffix(&self, domain: &str) -> bool {
+        self.public_suffix(domain)
+            .is_some_and(|public_suffix| canonicalize_host(domain) == Some(public_suffix))
+    }
+}
+
+impl SetCookie {
+    /// Check the `Domain` attribute against the list, as RFC 6265 §5.3 step 5 requires of a
+    /// cookie received from `request_host`.
+    pub fn check_public_suffix(
+        &self,
+        request_host: &str,
+        list: &PublicSuffixList,
+    ) -> PublicSuffixCheck {
+        let Some(domain) = self.domain.as_ref().and_then(canonicalize_domain) else {
+            return PublicSuffixCheck::Allowed;
+        };
+
+        if !list.is_public_suffix(&domain) {
+            PublicSuffixCheck::Allowed
+        } else if canonicalize_host(request_host) == Some(domain) {
+            PublicSuffixCheck::HostOnly
+        } else {
+            PublicSuffixCheck::Rejected
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use pest::Parser;
+
+use crate::{
+    apply_attribute, lenient, parse_cookie_date, parse_cookie_pair, CookiePairRef,
+    CookieParseError, Grammar, Rule, SetCookie, SetCookieRef, SyntaxError,
+};
+
+/// A problem found while parsing a set-cookie string in recovering mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SetCookieProblem {
+    /// The name-value pair does not match the grammar.
+    InvalidPair(SyntaxError),
+    /// An attribute, or the `"; "` in front of it, does not match the grammar.
+    InvalidAttribute(SyntaxError),
+    /// An attribute appeared more than once. The last occurrence wins.
+    DuplicateAttribute { name: String, offset: usize },
+    /// An attribute matched the grammar, but its value could not be interpreted.
+    InvalidAttributeValue {
+        name: String,
+        value: String,
+        offset: usize,
+    },
+}
+
+/// The result of parsing a set-cookie string in recovering mode.
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecoveredSetCookie {
+    /// Everything that could be parsed. If the name-value pair does not match the grammar it is
+    /// recovered as in lenient mode, or left empty when even that fails.
+    pub set_cookie: SetCookie,
+    /// Every problem found, in the order of appearance.
+    pub problems: Vec<SetCookieProblem>,
+}
+
+/// Parse a set-cookie string, collecting every problem instead of stopping at the first one.
+///
+/// Each `;`-separated part of the header is checked against the strict grammar on its own, so
+/// a bad attribute does not prevent the remaining ones from being parsed. When the header is
+/// valid, the result matches `parse_set_cookie` and the only problems reported are duplicate
+/// attributes and `Expires` values that are not cookie-dates, which the parser ignores.
+pub fn parse_set_cookie_recovering(input: &str) -> RecoveredSetCookie {
+    let mut problems = Vec::new();
+
+    let name_value_pair = input.split(';').next().unwrap_or(input);
+
+    let cookie_pair = match Grammar::parse(Rule::single_set_cookie_pair, name_value_pair) {
+        Ok(mut pairs) => pairs
+            .next()
+            .and_then(|single| single.into_inner().next())
+            .and_then(|pair| parse_cookie_pair(&pair).ok()),
+        Err(err) => {
+            problems.push(SetCookieProblem::InvalidPair(SyntaxError::from_pest(
+                input, 0, err,
+            )));
+
+            lenient::parse_cookie_pair(name_value_pair)
+        }
+    };
+
+    let mut set_cookie = SetCookieRef::from_pair(cookie_pair.unwrap_or(CookiePairRef {
+        name: "",
+        value: "",
+        quoted: false,
+    }));
+
+    let mut seen_attributes = Vec::new();
+
+    for (separator, _) in input.match_indices(';') {
+        let segment_start = separator + 1;
+        let segment = input[segment_start..].split(';').next().unwrap_or("");
+
+        // Mirror `";" ~ WHITE_SPACE ~ cookie_attribute`, but keep going without the whitespace.
+        let attribute_start = match segment.chars().next() {
+            Some(c) if c.is_whitespace() => segment_start + c.len_utf8(),
+            _ => {
+                problems.push(SetCookieProblem::InvalidAttribute(SyntaxError::new(
+                    input,
+                    segment_start,
+                    vec![String::from("' ' after ';'")],
+                )));
+
+                segment_start
+            }
+        };
+
+        let attribute = &input[attribute_start..segment_start + segment.len()];
+
+        let cookie_attribute = match Grammar::parse(Rule::single_cookie_attribute, attribute)
+            .map(|mut pairs| pairs.next().and_then(|single| single.into_inner().next()))
+        {
+            Ok(Some(cookie_attribute)) => cookie_attribute,
+            Ok(None) => continue,
+            Err(err) => {
+                problems.push(SetCookieProblem::InvalidAttribute(SyntaxError::from_pest(
+                    input,
+                    attribute_start,
+                    err,
+                )));
+
+                continue;
+            }
+        };
+
+        if let Some(inner_attribute) = cookie_attribute.clone().into_inner().next() {
+            let rule = inner_attribute.as_rule();
+
+            if rule != Rule::cookie_extension_attribute {
+                if seen_attributes.contains(&rule) {
+                    let name = inner_attribute
+                        .clone()
+                        .into_inner()
+                        .next()
+                        .map_or(inner_attribute.as_str(), |name| name.as_str());
+
+                    problems.push(SetCookieProblem::DuplicateAttribute {
+                        name: String::from(name),
+                        offset: attribute_start,
+                    });
+                } else {
+                    seen_attributes.push(rule);
+                }
+            }
+
+            // The parser ignores an Expires that is not a cookie-date, but it is still a problem.
+            if rule == Rule::cookie_expires_attribute {
+                let mut parts = inner_attribute.clone().into_inner();
+
+                if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+                    if parse_cookie_date(value.as_str()).is_none() {
+                        problems.push(SetCookieProblem::InvalidAttributeValue {
+                            name: String::from(name.as_str()),
+                            value: String::from(value.as_str()),
+                            offset: attribute_start,
+                        });
+                    }
+                }
+            }
+        }
+
+        match apply_attribute(&mut set_cookie, cookie_attribute) {
+            Ok(()) => {}
+            Err(CookieParseError::ErrorInvalidAttributeValue { name, value }) => {
+                problems.push(SetCookieProblem::InvalidAttributeValue {
+                    name,
+                    value,
+                    offset: attribute_start,
+                });
+            }
+            Err(CookieParseError::ErrorCookieStringSyntax(error)) => {
+                problems.push(SetCookieProblem::InvalidAttribute(error));
+            }
+            Err(CookieParseError::ErrorCookieStringEmpty)
+            | Err(CookieParseError::ErrorDuplicateCookieName { .. }) => {}
+        }
+    }
+
+    RecoveredSetCookie {
+        set_cookie: set_cookie.into_owned(),
+        problems,
+    }
+}
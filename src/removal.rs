@@ -0,0 +1,48 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{CookieDomain, CookiePair, SetCookie};
+
+impl SetCookie {
+    /// A set-cookie structure that removes the cookie with the given name, `Path` and `Domain`.
+    ///
+    /// User agents only replace a cookie with the same name, domain and path, so these have to
+    /// match the ones the cookie was set with. The value is empty, `Max-Age` is 0 and `Expires`
+    /// is the Unix epoch, for user agents that do not support `Max-Age`.
+    pub fn removal(name: &str, path: Option<&str>, domain: Option<CookieDomain>) -> SetCookie {
+        let mut set_cookie = SetCookie::from_pair(CookiePair {
+            name: String::from(name),
+            value: String::new(),
+            quoted: false,
+        });
+
+        set_cookie.path = path.map(String::from);
+        set_cookie.domain = domain;
+        set_cookie.max_age = Some(Duration::zero());
+        set_cookie.expires = Some(DateTime::UNIX_EPOCH);
+
+        set_cookie
+    }
+
+    /// A set-cookie structure that removes this cookie.
+    ///
+    /// Besides the name, `Path` and `Domain`, the `Secure`, `HttpOnly`, `SameSite` and
+    /// `Partitioned` attributes are kept, since user agents refuse to overwrite secure cookies
+    /// from insecure contexts and keep partitioned cookies apart from unpartitioned ones.
+    pub fn to_removal(&self) -> SetCookie {
+        let mut set_cookie =
+            SetCookie::removal(&self.pair.name, self.path.as_deref(), self.domain.clone());
+
+        set_cookie.secure = self.secure;
+        set_cookie.http_only = self.http_only;
+        set_cookie.same_site = self.same_site.clone();
+        set_cookie.partitioned = self.partitioned;
+
+        set_cookie
+    }
+
+    /// Whether this set-cookie structure removes the cookie when received at `now`, i.e. its
+    /// `Max-Age` is not positive or, without `Max-Age`, its `Expires` is not after `now`.
+    pub fn is_removal(&self, now: DateTime<Utc>) -> bool {
+        self.expiry_time(now).is_some_and(|expiry| expiry <= now)
+    }
+}
@@ -0,0 +1,199 @@
+// Serialize/Deserialize implementations that differ from the derived ones, enabled by the
+// `serde` feature. Attribute values are represented the way they are written in headers.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    builder::is_extension, CookieBuildError, CookieDomain, CookieHeader, CookiePair,
+    CookieParseError, DuplicatePolicy, Priority, SameSite, SetCookie,
+};
+
+// "Strict", "Lax", "None" or the unrecognized value
+impl Serialize for SameSite {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SameSite {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        Ok(SameSite::parse(&value))
+    }
+}
+
+// "Low", "Medium" or "High"
+impl Serialize for Priority {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Priority {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        Priority::parse(&value)
+            .ok_or_else(|| D::Error::custom(format!("invalid Priority '{}'", value)))
+    }
+}
+
+// "example.com", "127.0.0.1" or "[::1]"
+impl Serialize for CookieDomain {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CookieDomain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        CookieDomain::parse(&value)
+            .ok_or_else(|| D::Error::custom(format!("invalid Domain '{}'", value)))
+    }
+}
+
+// Max-Age as a number of seconds
+pub(crate) mod max_age {
+    use chrono::Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{max_age_from_seconds, max_age_to_seconds};
+
+    pub(crate) fn serialize<S: Serializer>(
+        max_age: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        max_age.map(max_age_to_seconds).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<i64>::deserialize(deserializer)?.map(max_age_from_seconds))
+    }
+}
+
+// Deserialized structures are checked so that they serialize to a header with the same structure:
+// a `;` or a CTL other than HTAB would end an attribute or the header line early. The lenient
+// parser accepts more than the grammar, so values are not checked against it.
+fn is_header_safe(value: &str) -> bool {
+    !value.contains(';') && !value.chars().any(|c| c.is_ascii_control() && c != '\t')
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CookiePairFields {
+    name: String,
+    value: String,
+    quoted: bool,
+}
+
+impl TryFrom<CookiePairFields> for CookiePair {
+    type Error = CookieBuildError;
+
+    fn try_from(fields: CookiePairFields) -> Result<Self, Self::Error> {
+        if !is_header_safe(&fields.name) || fields.name.contains('=') {
+            return Err(CookieBuildError::ErrorInvalidName(fields.name));
+        }
+
+        if !is_header_safe(&fields.value) || (fields.quoted && fields.value.contains('"')) {
+            return Err(CookieBuildError::ErrorInvalidValue(fields.value));
+        }
+
+        Ok(CookiePair {
+            name: fields.name,
+            value: fields.value,
+            quoted: fields.quoted,
+        })
+    }
+}
+
+// A cookie jar stores whatever pair was inserted, including ones from the lenient parser that
+// fail the checks above, so stored cookies are read back without them.
+pub(crate) fn unchecked_cookie_pair<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<CookiePair, D::Error> {
+    let fields = CookiePairFields::deserialize(deserializer)?;
+
+    Ok(CookiePair {
+        name: fields.name,
+        value: fields.value,
+        quoted: fields.quoted,
+    })
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SetCookieFields {
+    pair: CookiePair,
+    secure: bool,
+    http_only: bool,
+    #[serde(with = "max_age")]
+    max_age: Option<Duration>,
+    domain: Option<CookieDomain>,
+    expires: Option<DateTime<Utc>>,
+    path: Option<String>,
+    same_site: Option<SameSite>,
+    partitioned: bool,
+    priority: Option<Priority>,
+    extensions: Vec<String>,
+}
+
+impl TryFrom<SetCookieFields> for SetCookie {
+    type Error = CookieBuildError;
+
+    fn try_from(fields: SetCookieFields) -> Result<Self, Self::Error> {
+        let invalid = |name: &str, value: &str| CookieBuildError::ErrorInvalidAttributeValue {
+            name: String::from(name),
+            value: String::from(value),
+        };
+
+        if let Some(path) = fields.path.as_deref().filter(|path| !is_header_safe(path)) {
+            return Err(invalid("Path", path));
+        }
+
+        if let Some(same_site) = &fields.same_site {
+            if !is_header_safe(same_site.as_str()) {
+                return Err(invalid("SameSite", same_site.as_str()));
+            }
+        }
+
+        // Known attributes such as `Secure` would be read back as themselves.
+        for extension in &fields.extensions {
+            if !is_header_safe(extension) || !is_extension(extension) {
+                return Err(invalid("extension", extension));
+            }
+        }
+
+        Ok(SetCookie {
+            pair: fields.pair,
+            secure: fields.secure,
+            http_only: fields.http_only,
+            max_age: fields.max_age,
+            domain: fields.domain,
+            expires: fields.expires,
+            path: fields.path,
+            same_site: fields.same_site,
+            partitioned: fields.partitioned,
+            priority: fields.priority,
+            extensions: fields.extensions,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CookieHeaderFields {
+    pairs: Vec<CookiePair>,
+    policy: DuplicatePolicy,
+}
+
+// Duplicate names are rejected again under `DuplicatePolicy::Error`.
+impl TryFrom<CookieHeaderFields> for CookieHeader {
+    type Error = CookieParseError;
+
+    fn try_from(fields: CookieHeaderFields) -> Result<Self, Self::Error> {
+        CookieHeader::from_pairs(fields.pairs, fields.policy)
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt;
+
+use crate::{max_age_to_seconds, CookieHeader, CookiePair, SetCookie};
+
+/// Conversion of parsed values back into header values.
+///
+/// For any value produced by the parser, parsing the header string again gives back an equal
+/// value.
+pub trait ToHeaderString {
+    fn to_header_string(&self) -> String;
+}
+
+impl fmt::Display for CookiePair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.raw_value())
+    }
+}
+
+// Attributes are written in a fixed order, so equal values always produce the same header.
+impl fmt::Display for SetCookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pair)?;
+
+        if let Some(expires) = self.expires {
+            // IMF-fixdate: https://datatracker.ietf.org/doc/html/rfc7231#section-7.1.1.1
+            write!(
+                f,
+                "; Expires={}",
+                expires.format("%a, %d %b %Y %H:%M:%S GMT")
+            )?;
+        }
+
+        if let Some(max_age) = self.max_age {
+            write!(f, "; Max-Age={}", max_age_to_seconds(max_age))?;
+        }
+
+        if let Some(domain) = &self.domain {
+            write!(f, "; Domain={}", domain)?;
+        }
+
+        if let Some(path) = &self.path {
+            write!(f, "; Path={}", path)?;
+        }
+
+        if self.secure {
+            write!(f, "; Secure")?;
+        }
+
+        if self.http_only {
+            write!(f, "; HttpOnly")?;
+        }
+
+        if let Some(same_site) = &self.same_site {
+            write!(f, "; SameSite={}", same_site.as_str())?;
+        }
+
+        if self.partitioned {
+            write!(f, "; Partitioned")?;
+        }
+
+        if let Some(priority) = self.priority {
+            write!(f, "; Priority={}", priority.as_str())?;
+        }
+
+        for extension in &self.extensions {
+            write!(f, "; {}", extension)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for CookieHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, pair) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+
+            write!(f, "{}", pair)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ToHeaderString for CookiePair {
+    fn to_header_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToHeaderString for SetCookie {
+    fn to_header_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToHeaderString for CookieHeader {
+    fn to_header_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// A Cookie header value, with the pairs separated by `; `.
+impl ToHeaderString for [CookiePair] {
+    fn to_header_string(&self) -> String {
+        self.iter()
+            .map(CookiePair::to_string)
+            .collect::<Vec<String>>()
+            .join("; ")
+    }
+}
@@ -0,0 +1,106 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use cookie_parser::{
+        parse_cookie_string, parse_cookie_string_borrowed, parse_set_cookie,
+        parse_set_cookie_borrowed, CookieDomain, CookiePairRef, SameSite, SetCookieRef,
+    };
+
+    #[test]
+    fn test_parse_cookie_string_borrowed_points_into_input() {
+        let input = String::from("cookie_name1=cookie_value1; cookie_name2=\"cookie_value2\"");
+
+        let actual = parse_cookie_string_borrowed(&input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(
+                    result,
+                    vec![
+                        CookiePairRef {
+                            name: "cookie_name1",
+                            value: "cookie_value1",
+                            quoted: false,
+                        },
+                        CookiePairRef {
+                            name: "cookie_name2",
+                            value: "cookie_value2",
+                            quoted: true,
+                        },
+                    ]
+                );
+
+                let input_range = input.as_bytes().as_ptr_range();
+
+                assert!(input_range.contains(&result[0].name.as_ptr()));
+                assert!(input_range.contains(&result[1].value.as_ptr()));
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_borrowed() {
+        let input = "id=a3fWa; Max-Age=60; Domain=Example.com; Path=/docs; SameSite=Lax; Foo=bar";
+
+        let expected = SetCookieRef {
+            pair: CookiePairRef {
+                name: "id",
+                value: "a3fWa",
+                quoted: false,
+            },
+            domain: Some(CookieDomain::Host(String::from("example.com"))),
+            max_age: Some(Duration::seconds(60)),
+            path: Some("/docs"),
+            expires: None,
+            same_site: Some(SameSite::Lax),
+            partitioned: false,
+            priority: None,
+            http_only: false,
+            secure: false,
+            extensions: vec!["Foo=bar"],
+        };
+
+        let actual = parse_set_cookie_borrowed(input);
+
+        match actual {
+            Ok(result) => assert_eq!(result, expected),
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_borrowed_into_owned_matches_owned_parsers() {
+        let cookie_string = "a=1; b=\"2\"; c=3";
+        let set_cookie_string = "id=\"a3fWa\"; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Secure; HttpOnly; Priority=High; Partitioned";
+
+        let borrowed_pairs: Vec<_> = parse_cookie_string_borrowed(cookie_string)
+            .unwrap()
+            .into_iter()
+            .map(CookiePairRef::into_owned)
+            .collect();
+
+        assert_eq!(borrowed_pairs, parse_cookie_string(cookie_string).unwrap());
+        assert_eq!(
+            parse_set_cookie_borrowed(set_cookie_string)
+                .unwrap()
+                .into_owned(),
+            parse_set_cookie(set_cookie_string).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_set_cookie_borrowed_invalid() {
+        let input = "id=a3fWa;Secure";
+
+        let actual = parse_set_cookie_borrowed(input);
+
+        match actual {
+            Ok(_) => assert!(false),
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(_)) => assert!(true),
+            Err(_) => assert!(false),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use cookie_parser::{
+        parse_set_cookie, CookieBuildError, CookieDomain, CookiePair, Priority, SameSite, SetCookie,
+    };
+
+    #[test]
+    fn test_set_cookie_builder() {
+        let actual = SetCookie::builder("id", "a3fWa")
+            .expires(Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap())
+            .max_age(Duration::seconds(60))
+            .domain(".Example.com")
+            .path("/docs")
+            .secure(true)
+            .http_only(true)
+            .same_site(SameSite::Lax)
+            .priority(Priority::High)
+            .extension("Foo=bar")
+            .build();
+
+        let expected = SetCookie {
+            pair: CookiePair {
+                name: String::from("id"),
+                value: String::from("a3fWa"),
+                quoted: false,
+            },
+            domain: Some(CookieDomain::Host(String::from("example.com"))),
+            max_age: Some(Duration::seconds(60)),
+            path: Some(String::from("/docs")),
+            expires: Some(Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap()),
+            same_site: Some(SameSite::Lax),
+            partitioned: false,
+            priority: Some(Priority::High),
+            http_only: true,
+            secure: true,
+            extensions: vec![String::from("Foo=bar")],
+        };
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result, expected);
+                assert_eq!(parse_set_cookie(&result.to_string()).unwrap(), result);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_set_cookie_builder_quoted_value() {
+        match SetCookie::builder("id", "abc==").quoted(true).build() {
+            Ok(result) => assert_eq!(result.to_string(), "id=\"abc==\""),
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_set_cookie_builder_invalid_name_and_value() {
+        assert_eq!(
+            SetCookie::builder("a=b", "1").build(),
+            Err(CookieBuildError::ErrorInvalidName(String::from("a=b")))
+        );
+        assert_eq!(
+            SetCookie::builder("", "1").build(),
+            Err(CookieBuildError::ErrorInvalidName(String::new()))
+        );
+        assert_eq!(
+            SetCookie::builder("id", "a;b").build(),
+            Err(CookieBuildError::ErrorInvalidValue(String::from("a;b")))
+        );
+        assert_eq!(
+            SetCookie::builder("id", "a b").quoted(true).build(),
+            Err(CookieBuildError::ErrorInvalidValue(String::from("a b")))
+        );
+    }
+
+    #[test]
+    fn test_set_cookie_builder_invalid_attributes() {
+        let invalid = |name: &str, value: &str| {
+            Err(CookieBuildError::ErrorInvalidAttributeValue {
+                name: String::from(name),
+                value: String::from(value),
+            })
+        };
+
+        assert_eq!(
+            SetCookie::builder("id", "1").domain("exa mple.com").build(),
+            invalid("Domain", "exa mple.com")
+        );
+        assert_eq!(
+            SetCookie::builder("id", "1").domain("[1:2]").build(),
+            invalid("Domain", "[1:2]")
+        );
+        assert_eq!(
+            SetCookie::builder("id", "1").path("/a;b").build(),
+            invalid("Path", "/a;b")
+        );
+        assert_eq!(
+            SetCookie::builder("id", "1").path(" /a").build(),
+            invalid("Path", " /a")
+        );
+        assert_eq!(
+            SetCookie::builder("id", "1").path("/\r\nX-Evil: 1").build(),
+            invalid("Path", "/\r\nX-Evil: 1")
+        );
+        assert_eq!(
+            SetCookie::builder("id", "1")
+                .same_site(SameSite::Other(String::from("")))
+                .build(),
+            invalid("SameSite", "")
+        );
+        assert_eq!(
+            SetCookie::builder("id", "1")
+                .same_site(SameSite::Other(String::from("Lax\r\nX: 1")))
+                .build(),
+            invalid("SameSite", "Lax\r\nX: 1")
+        );
+        assert_eq!(
+            SetCookie::builder("id", "1").extension("Secure").build(),
+            invalid("extension", "Secure")
+        );
+        assert_eq!(
+            SetCookie::builder("id", "1")
+                .extension("Foo=\r\nX-Evil: 1")
+                .build(),
+            invalid("extension", "Foo=\r\nX-Evil: 1")
+        );
+        assert_eq!(
+            SetCookie::builder("id", "1")
+                .extension("Max-Age=60")
+                .build(),
+            invalid("extension", "Max-Age=60")
+        );
+        assert_eq!(
+            SetCookie::builder("id", "1")
+                .expires(Utc.with_ymd_and_hms(1500, 1, 1, 0, 0, 0).unwrap())
+                .build(),
+            invalid("Expires", "Mon, 01 Jan 1500 00:00:00 GMT")
+        );
+    }
+
+    #[test]
+    fn test_set_cookie_builder_drops_fractions_of_a_second() {
+        let expires = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+
+        let actual = SetCookie::builder("id", "1")
+            .expires(expires + Duration::milliseconds(750))
+            .max_age(Duration::milliseconds(1500))
+            .build();
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.expires, Some(expires));
+                assert_eq!(result.max_age, Some(Duration::seconds(1)));
+                assert_eq!(parse_set_cookie(&result.to_string()).unwrap(), result);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+}
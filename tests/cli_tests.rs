@@ -0,0 +1,69 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        process::{self, Command},
+    };
+
+    // Run the CLI on a file holding `input` and return its error report.
+    fn report(name: &str, command: &str, input: &str) -> String {
+        let path = env::temp_dir().join(format!("cookie_parser_cli_{}_{}", name, process::id()));
+        fs::write(&path, input).unwrap();
+
+        let output = Command::new(env!("CARGO_BIN_EXE_cookie_parser"))
+            .args([command, "--file"])
+            .arg(&path)
+            .env("NO_COLOR", "1")
+            .output()
+            .unwrap();
+
+        fs::remove_file(&path).unwrap();
+        assert!(!output.status.success());
+
+        String::from_utf8(output.stderr).unwrap()
+    }
+
+    #[test]
+    fn test_report_missing_space_after_semicolon() {
+        let actual = report("semicolon", "parse-cookie", "a=b;c=d");
+
+        assert!(actual.contains("1 | a=b;c=d\n  |    ^\n"));
+        assert!(actual.contains(
+            "= rule: cookie_string and set_cookie_string separate entries with exactly \"; \""
+        ));
+        assert!(actual.contains("= help: add a space after ';'\n"));
+    }
+
+    #[test]
+    fn test_report_invalid_cookie_value() {
+        let actual = report("value", "parse-cookie", "a=,b");
+
+        assert!(actual.contains("1 | a=,b\n  |   ^\n"));
+        assert!(actual.contains("= rule: cookie_value is one or more cookie-octets"));
+        assert!(actual
+            .contains("= help: percent-encode characters that are not allowed in cookie values\n"));
+    }
+
+    #[test]
+    fn test_report_unclosed_quoted_cookie_value() {
+        let actual = report("quoted", "parse-cookie", "a=\"b");
+
+        assert!(actual.contains("= help: close the quoted value with '\"'\n"));
+    }
+
+    #[test]
+    fn test_report_invalid_attribute_value() {
+        let actual = report(
+            "attribute",
+            "parse-set-cookie",
+            "Domain=[1:2:3]; Domain=[1:2:3]",
+        );
+
+        let expected = "error: Invalid value '[1:2:3]' for attribute 'Domain'\n  |\n1 | Domain=[1:2:3]; Domain=[1:2:3]\n  |                        ^\n";
+
+        assert!(actual.starts_with(expected));
+        assert!(actual.contains("= help: use a host name such as example.com"));
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use cookie_parser::parse_cookie_date;
+
+    #[test]
+    fn test_parse_cookie_date_rfc1123() {
+        let actual = parse_cookie_date("Wed, 21 Oct 2015 07:28:00 GMT");
+
+        assert_eq!(
+            actual,
+            Some(Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_cookie_date_rfc850() {
+        let actual = parse_cookie_date("Wednesday, 21-Oct-15 07:28:00 GMT");
+
+        assert_eq!(
+            actual,
+            Some(Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_cookie_date_asctime() {
+        let actual = parse_cookie_date("Wed Oct 21 07:28:00 2015");
+
+        assert_eq!(
+            actual,
+            Some(Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_cookie_date_two_digit_years() {
+        let actual_1900s = parse_cookie_date("Thu, 01-Jan-70 00:00:01 GMT");
+        let actual_2000s = parse_cookie_date("Sun, 01-Jan-69 00:00:01 GMT");
+
+        assert_eq!(
+            actual_1900s,
+            Some(Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 1).unwrap())
+        );
+        assert_eq!(
+            actual_2000s,
+            Some(Utc.with_ymd_and_hms(2069, 1, 1, 0, 0, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_cookie_date_out_of_order_tokens() {
+        let actual = parse_cookie_date("2015 07:28:00 october 21");
+
+        assert_eq!(
+            actual,
+            Some(Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_cookie_date_missing_time() {
+        let actual = parse_cookie_date("Wed, 21 Oct 2015");
+
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn test_parse_cookie_date_out_of_range() {
+        assert_eq!(parse_cookie_date("Wed, 32 Oct 2015 07:28:00 GMT"), None);
+        assert_eq!(parse_cookie_date("Wed, 21 Oct 2015 24:28:00 GMT"), None);
+        assert_eq!(parse_cookie_date("Mon, 30 Feb 2015 07:28:00 GMT"), None);
+        assert_eq!(parse_cookie_date("Mon, 01 Jan 1600 07:28:00 GMT"), None);
+    }
+}
@@ -0,0 +1,215 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use std::mem::discriminant;
+
+    use cookie_parser::{
+        parse_cookie_string, parse_cookie_string_fast, parse_set_cookie, parse_set_cookie_fast,
+    };
+
+    const COOKIE_PAIRS: [&str; 14] = [
+        "a=1",
+        "session=abc==",
+        "id=\"a3fWa\"",
+        "path=/a/b:c",
+        "k=v=w",
+        "x=",
+        "=1",
+        "y=\"\"",
+        "z=\"open",
+        "name with space=1",
+        "sp=a b",
+        "é=ü",
+        "q=a\\b",
+        "c=1,2",
+    ];
+
+    const ATTRIBUTES: [&str; 53] = [
+        "Expires=Wed, 21 Oct 2015 07:28:00 GMT",
+        "expires = Wednesday, 21-Oct-15 07:28:00 GMT",
+        "Expires=Wed Oct 21 07:28:00 2015",
+        "Expires=not a date",
+        "Expires=",
+        "Expires= ",
+        "Max-Age=60",
+        "max-age = -1",
+        "Max-Age=0",
+        "Max-Age=99999999999999999999",
+        "Max-Age=12abc",
+        "Max-Age=abc",
+        "Max-Age=",
+        "Max-Age=-",
+        "Domain=example.com",
+        "domain=.My-Site.COM",
+        "Domain=xn--bcher-kva.example",
+        "Domain=bücher.example",
+        "Domain=127.0.0.1",
+        "Domain=[::1]",
+        "Domain=[1:2]",
+        "Domain=[zz]",
+        "Domain=example.com.",
+        "Domain=a..b",
+        "Domain=",
+        "Path=/",
+        "path=/docs; x",
+        "Path=",
+        "Secure",
+        "secure",
+        "SecureX",
+        "Secure=1",
+        "Secure ",
+        "secure-ish=1",
+        "HttpOnly",
+        "HTTPONLY",
+        "HttpOnlyX",
+        "SameSite=Lax",
+        "samesite=strict",
+        "SameSite=Bogus",
+        "SameSite=Lax ",
+        "SameSite = Strict \t",
+        "Partitioned",
+        "Partitioned=1",
+        "partitionedX",
+        "Priority=High",
+        "priority=low",
+        "Priority=Lowest",
+        "Priority=Urgent",
+        "Foo=bar",
+        "Foo",
+        "",
+        "\u{3000}",
+    ];
+
+    const DELIMITERS: [&str; 7] = ["; ", "; ", "; ", ";", ";  ", ";\t", ";\u{3000}"];
+
+    // A deterministic linear congruential generator, so failures are reproducible.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self, bound: usize) -> usize {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            ((self.0 >> 33) as usize) % bound
+        }
+
+        fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+            items[self.next(items.len())]
+        }
+    }
+
+    fn generate(lcg: &mut Lcg, parts: &[&str]) -> String {
+        let mut input = String::from(lcg.pick(&COOKIE_PAIRS));
+
+        for _ in 0..lcg.next(6) {
+            input.push_str(lcg.pick(&DELIMITERS));
+            input.push_str(lcg.pick(parts));
+        }
+
+        // Occasionally break the input at a random character boundary.
+        if lcg.next(8) == 0 {
+            let cut = lcg.next(input.len() + 1);
+
+            if input.is_char_boundary(cut) {
+                input.truncate(cut);
+            }
+        }
+
+        input
+    }
+
+    #[test]
+    fn test_parse_set_cookie_fast() {
+        let input = "id=\"a3fWa\"; Expires=Wed, 21 Oct 2015 07:28:00 GMT; max-age = 60; Domain=.Example.com; Path=/docs; Secure; HttpOnly; SameSite=Lax; Partitioned; Priority=High; Foo=bar";
+
+        match parse_set_cookie_fast(input) {
+            Ok(result) => assert_eq!(result, parse_set_cookie(input).unwrap()),
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_fast_invalid_value_after_syntax_error() {
+        let input = "id=a3fWa; Expires=not a date; Max-Age=12abc";
+
+        match parse_set_cookie_fast(input) {
+            Ok(_) => assert!(false),
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(error)) => {
+                assert_eq!(error.offset, 40);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_string_fast_agrees_with_grammar() {
+        let mut lcg = Lcg(0x5eed);
+        let mut accepted = 0;
+
+        for _ in 0..20_000 {
+            let input = generate(&mut lcg, &COOKIE_PAIRS);
+
+            match (
+                parse_cookie_string(&input),
+                parse_cookie_string_fast(&input),
+            ) {
+                (Ok(expected), Ok(actual)) => {
+                    assert_eq!(actual, expected, "input: {:?}", input);
+                    accepted += 1;
+                }
+                (Err(expected), Err(actual)) => {
+                    assert_eq!(
+                        discriminant(&actual),
+                        discriminant(&expected),
+                        "input: {:?}",
+                        input
+                    )
+                }
+                (expected, actual) => {
+                    panic!(
+                        "input: {:?}, grammar: {:?}, fast: {:?}",
+                        input, expected, actual
+                    )
+                }
+            }
+        }
+
+        assert!(accepted > 1_000);
+    }
+
+    #[test]
+    fn test_parse_set_cookie_fast_agrees_with_grammar() {
+        let mut lcg = Lcg(0xc00c1e);
+        let mut accepted = 0;
+
+        for _ in 0..20_000 {
+            let input = generate(&mut lcg, &ATTRIBUTES);
+
+            match (parse_set_cookie(&input), parse_set_cookie_fast(&input)) {
+                (Ok(expected), Ok(actual)) => {
+                    assert_eq!(actual, expected, "input: {:?}", input);
+                    accepted += 1;
+                }
+                (Err(expected), Err(actual)) => {
+                    assert_eq!(
+                        discriminant(&actual),
+                        discriminant(&expected),
+                        "input: {:?}",
+                        input
+                    )
+                }
+                (expected, actual) => {
+                    panic!(
+                        "input: {:?}, grammar: {:?}, fast: {:?}",
+                        input, expected, actual
+                    )
+                }
+            }
+        }
+
+        assert!(accepted > 1_000);
+    }
+}
@@ -1,6 +1,14 @@
+#![allow(clippy::assertions_on_constants)]
+
 #[cfg(test)]
 mod tests {
-    use cookie_parser::{parse_cookie_string, parse_set_cookie, CookiePair, SetCookie};
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use cookie_parser::{
+        parse_cookie_string, parse_set_cookie, CookieDomain, CookiePair, Priority, SameSite,
+        SetCookie, SyntaxError,
+    };
 
     #[test]
     fn test_parse_cookie_string_single_cookie() {
@@ -9,6 +17,7 @@ mod tests {
         let expected_pair = CookiePair {
             name: String::from("cookie_name"),
             value: String::from("cookie_value"),
+            quoted: false,
         };
 
         let actual = parse_cookie_string(input);
@@ -29,10 +38,12 @@ mod tests {
         let expected_pair_1 = CookiePair {
             name: String::from("cookie_name1"),
             value: String::from("cookie_value1"),
+            quoted: false,
         };
         let expected_pair_2 = CookiePair {
             name: String::from("cookie_name2"),
             value: String::from("cookie_value2"),
+            quoted: false,
         };
 
         let actual = parse_cookie_string(input);
@@ -54,7 +65,7 @@ mod tests {
         let actual = parse_cookie_string(input);
 
         match actual {
-            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax) => assert!(true),
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(_)) => assert!(true),
             _ => assert!(false),
         }
     }
@@ -66,7 +77,7 @@ mod tests {
         let actual = parse_cookie_string(input);
 
         match actual {
-            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax) => assert!(true),
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(_)) => assert!(true),
             _ => assert!(false),
         }
     }
@@ -78,7 +89,7 @@ mod tests {
         let actual = parse_cookie_string(input);
 
         match actual {
-            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax) => assert!(true),
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(_)) => assert!(true),
             _ => assert!(false),
         }
     }
@@ -90,8 +101,12 @@ mod tests {
         let actual = parse_cookie_string(input);
 
         match actual {
-            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax) => assert!(true),
-            _ => assert!(false),
+            Ok(result) => {
+                assert_eq!(result[0].name, "cookie_name1");
+                assert_eq!(result[0].value, "");
+                assert_eq!(result[1].value, "cookie_value_2");
+            }
+            Err(_) => assert!(false),
         }
     }
 
@@ -102,7 +117,7 @@ mod tests {
         let actual = parse_cookie_string(input);
 
         match actual {
-            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax) => assert!(true),
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(_)) => assert!(true),
             _ => assert!(false),
         }
     }
@@ -114,8 +129,41 @@ mod tests {
         let actual = parse_cookie_string(input);
 
         match actual {
-            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax) => assert!(true),
-            _ => assert!(false),
+            Ok(result) => {
+                assert_eq!(result.len(), 2);
+                assert_eq!(result[0].value, "cookie/value1");
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_cookie_octets_in_cookie_value() {
+        let input = "a=abc==; b=12:30; c=k=v; d=<{[(@?)]}>";
+
+        let actual = parse_cookie_string(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.len(), 4);
+                assert_eq!(result[0].value, "abc==");
+                assert_eq!(result[1].value, "12:30");
+                assert_eq!(result[2].value, "k=v");
+                assert_eq!(result[3].value, "<{[(@?)]}>");
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_non_cookie_octets_in_cookie_value() {
+        for input in ["a=b,c", "a=b\\c", "a=b c", "a=b\"c"] {
+            let actual = parse_cookie_string(input);
+
+            match actual {
+                Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(_)) => assert!(true),
+                _ => assert!(false),
+            }
         }
     }
 
@@ -129,11 +177,15 @@ mod tests {
             pair: CookiePair {
                 name: String::from("cookie_name"),
                 value: String::from("cookie_value"),
+                quoted: false,
             },
             domain: None,
             max_age: None,
             path: None,
             expires: None,
+            same_site: None,
+            partitioned: false,
+            priority: None,
             http_only: false,
             secure: false,
             extensions: vec![],
@@ -157,11 +209,15 @@ mod tests {
             pair: CookiePair {
                 name: String::from("cookie_name"),
                 value: String::from("cookie_value"),
+                quoted: false,
             },
             domain: None,
             max_age: None,
             path: None,
             expires: None,
+            same_site: None,
+            partitioned: false,
+            priority: None,
             http_only: true,
             secure: false,
             extensions: vec![],
@@ -185,11 +241,15 @@ mod tests {
             pair: CookiePair {
                 name: String::from("cookie_name"),
                 value: String::from("cookie_value"),
+                quoted: false,
             },
             domain: None,
             max_age: None,
             path: None,
             expires: None,
+            same_site: None,
+            partitioned: false,
+            priority: None,
             http_only: false,
             secure: true,
             extensions: vec![],
@@ -213,11 +273,15 @@ mod tests {
             pair: CookiePair {
                 name: String::from("cookie_name"),
                 value: String::from("cookie_value"),
+                quoted: false,
             },
-            domain: Some(String::from("google.com")),
+            domain: Some(CookieDomain::Host(String::from("google.com"))),
             max_age: None,
             path: None,
             expires: None,
+            same_site: None,
+            partitioned: false,
+            priority: None,
             http_only: false,
             secure: false,
             extensions: vec![],
@@ -241,11 +305,15 @@ mod tests {
             pair: CookiePair {
                 name: String::from("cookie_name"),
                 value: String::from("cookie_value"),
+                quoted: false,
             },
             domain: None,
             max_age: None,
             path: Some(String::from("/path/to/file")),
             expires: None,
+            same_site: None,
+            partitioned: false,
+            priority: None,
             http_only: false,
             secure: false,
             extensions: vec![],
@@ -261,7 +329,7 @@ mod tests {
 
     #[test]
     fn test_parse_set_cookie_expires() {
-        let input = "cookie_name=cookie_value; Expires=20.09.2044";
+        let input = "cookie_name=cookie_value; Expires=Wed, 21 Oct 2015 07:28:00 GMT";
 
         let actual = parse_set_cookie(input);
 
@@ -269,11 +337,15 @@ mod tests {
             pair: CookiePair {
                 name: String::from("cookie_name"),
                 value: String::from("cookie_value"),
+                quoted: false,
             },
             domain: None,
             max_age: None,
             path: None,
-            expires: Some(String::from("20.09.2044")),
+            expires: Some(Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap()),
+            same_site: None,
+            partitioned: false,
+            priority: None,
             http_only: false,
             secure: false,
             extensions: vec![],
@@ -297,11 +369,15 @@ mod tests {
             pair: CookiePair {
                 name: String::from("cookie_name"),
                 value: String::from("cookie_value"),
+                quoted: false,
             },
             domain: None,
-            max_age: Some(String::from("100000")),
+            max_age: Some(Duration::seconds(100000)),
             path: None,
             expires: None,
+            same_site: None,
+            partitioned: false,
+            priority: None,
             http_only: false,
             secure: false,
             extensions: vec![],
@@ -325,11 +401,15 @@ mod tests {
             pair: CookiePair {
                 name: String::from("cookie_name"),
                 value: String::from("cookie_value"),
+                quoted: false,
             },
             domain: None,
             max_age: None,
             path: None,
             expires: None,
+            same_site: None,
+            partitioned: false,
+            priority: None,
             http_only: false,
             secure: false,
             extensions: vec![String::from("MyKey=MyValue")],
@@ -353,11 +433,15 @@ mod tests {
             pair: CookiePair {
                 name: String::from("cookie_name"),
                 value: String::from("cookie_value"),
+                quoted: false,
             },
-            domain: Some(String::from("google.com")),
-            max_age: Some(String::from("100000")),
+            domain: Some(CookieDomain::Host(String::from("google.com"))),
+            max_age: Some(Duration::seconds(100000)),
             path: Some(String::from("/path/to/file")),
             expires: None,
+            same_site: None,
+            partitioned: false,
+            priority: None,
             http_only: false,
             secure: true,
             extensions: vec![],
@@ -370,4 +454,562 @@ mod tests {
             Err(_) => assert!(false),
         }
     }
+
+    #[test]
+    fn test_parse_set_cookie_expires_invalid_date() {
+        let input = "cookie_name=cookie_value; Expires=20.09.2044";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.expires, None);
+                assert!(result.extensions.is_empty());
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_max_age_zero() {
+        let input = "cookie_name=cookie_value; Max-Age=0";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.max_age, Some(Duration::zero()));
+                assert_eq!(
+                    result.expiry_time(Utc::now()),
+                    Some(DateTime::<Utc>::MIN_UTC)
+                );
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_max_age_negative() {
+        let input = "cookie_name=cookie_value; Max-Age=-1";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.max_age, Some(Duration::seconds(-1)));
+                assert_eq!(
+                    result.expiry_time(Utc::now()),
+                    Some(DateTime::<Utc>::MIN_UTC)
+                );
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_max_age_saturates() {
+        let input = "cookie_name=cookie_value; Max-Age=99999999999999999999999";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.max_age, Some(Duration::MAX));
+                assert_eq!(
+                    result.expiry_time(Utc::now()),
+                    Some(DateTime::<Utc>::MAX_UTC)
+                );
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_set_cookie_expiry_time_max_age_precedence() {
+        let input = "cookie_name=cookie_value; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Max-Age=60";
+        let now = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(
+                    result.expiry_time(now),
+                    Some(Utc.with_ymd_and_hms(2020, 1, 1, 0, 1, 0).unwrap())
+                );
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_same_site() {
+        let input = "cookie_name=cookie_value; SameSite=Lax";
+
+        let actual = parse_set_cookie(input);
+
+        let expected = SetCookie {
+            pair: CookiePair {
+                name: String::from("cookie_name"),
+                value: String::from("cookie_value"),
+                quoted: false,
+            },
+            domain: None,
+            max_age: None,
+            path: None,
+            expires: None,
+            same_site: Some(SameSite::Lax),
+            partitioned: false,
+            priority: None,
+            http_only: false,
+            secure: false,
+            extensions: vec![],
+        };
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result, expected);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_same_site_case_insensitive() {
+        let strict = parse_set_cookie("cookie_name=cookie_value; SameSite=STRICT");
+        let none = parse_set_cookie("cookie_name=cookie_value; SameSite=none");
+
+        match (strict, none) {
+            (Ok(strict), Ok(none)) => {
+                assert_eq!(strict.same_site, Some(SameSite::Strict));
+                assert_eq!(none.same_site, Some(SameSite::None));
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_same_site_trailing_whitespace() {
+        for input in [
+            "cookie_name=cookie_value; SameSite=Lax ",
+            "cookie_name=cookie_value; SameSite = Lax\t; Secure",
+        ] {
+            let actual = parse_set_cookie(input);
+
+            match actual {
+                Ok(result) => {
+                    assert_eq!(result.same_site, Some(SameSite::Lax));
+                    assert!(result.extensions.is_empty());
+                }
+                Err(_) => assert!(false),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_same_site_unknown_value() {
+        let input = "cookie_name=cookie_value; SameSite=Sometimes";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(
+                    result.same_site,
+                    Some(SameSite::Other(String::from("Sometimes")))
+                );
+                assert!(result.extensions.is_empty());
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_partitioned() {
+        let input = "cookie_name=cookie_value; Secure; Partitioned";
+
+        let actual = parse_set_cookie(input);
+
+        let expected = SetCookie {
+            pair: CookiePair {
+                name: String::from("cookie_name"),
+                value: String::from("cookie_value"),
+                quoted: false,
+            },
+            domain: None,
+            max_age: None,
+            path: None,
+            expires: None,
+            same_site: None,
+            partitioned: true,
+            priority: None,
+            http_only: false,
+            secure: true,
+            extensions: vec![],
+        };
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result, expected);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_priority() {
+        let low = parse_set_cookie("cookie_name=cookie_value; Priority=Low");
+        let medium = parse_set_cookie("cookie_name=cookie_value; Priority=medium");
+        let high = parse_set_cookie("cookie_name=cookie_value; Priority=HIGH");
+
+        match (low, medium, high) {
+            (Ok(low), Ok(medium), Ok(high)) => {
+                assert_eq!(low.priority, Some(Priority::Low));
+                assert_eq!(medium.priority, Some(Priority::Medium));
+                assert_eq!(high.priority, Some(Priority::High));
+                assert!(high.extensions.is_empty());
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_priority_unknown_value() {
+        for input in [
+            "cookie_name=cookie_value; Priority=Urgent",
+            "cookie_name=cookie_value; Priority=Lowest",
+        ] {
+            let actual = parse_set_cookie(input);
+
+            match actual {
+                Ok(result) => {
+                    assert_eq!(result.priority, None);
+                    assert!(result.extensions.is_empty());
+                }
+                Err(_) => assert!(false),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_partitioned_with_value_is_extension() {
+        let input = "cookie_name=cookie_value; Partitioned=1";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Ok(result) => {
+                assert!(!result.partitioned);
+                assert_eq!(result.extensions, vec![String::from("Partitioned=1")]);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_case_insensitive_attribute_names() {
+        let input = "cookie_name=cookie_value; max-age=60; path=/; DOMAIN=google.com; secure; HTTPONLY; samesite=strict";
+
+        let actual = parse_set_cookie(input);
+
+        let expected = SetCookie {
+            pair: CookiePair {
+                name: String::from("cookie_name"),
+                value: String::from("cookie_value"),
+                quoted: false,
+            },
+            domain: Some(CookieDomain::Host(String::from("google.com"))),
+            max_age: Some(Duration::seconds(60)),
+            path: Some(String::from("/")),
+            expires: None,
+            same_site: Some(SameSite::Strict),
+            partitioned: false,
+            priority: None,
+            http_only: true,
+            secure: true,
+            extensions: vec![],
+        };
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result, expected);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_flag_name_prefix_is_extension() {
+        let input = "a=b; secure-ish=1; HttpOnlyX";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Ok(result) => {
+                assert!(!result.secure);
+                assert!(!result.http_only);
+                assert_eq!(
+                    result.extensions,
+                    vec![String::from("secure-ish=1"), String::from("HttpOnlyX")]
+                );
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_whitespace_around_equals() {
+        let input = "cookie_name=cookie_value; Max-Age = 60; Path= /; Domain =google.com";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.max_age, Some(Duration::seconds(60)));
+                assert_eq!(result.path, Some(String::from("/")));
+                assert_eq!(
+                    result.domain,
+                    Some(CookieDomain::Host(String::from("google.com")))
+                );
+                assert!(result.extensions.is_empty());
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_invalid_value_reports_original_spelling() {
+        let input = "cookie_name=cookie_value; DOMAIN=[1:2:3]";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Err(cookie_parser::CookieParseError::ErrorInvalidAttributeValue { name, value }) => {
+                assert_eq!(name, "DOMAIN");
+                assert_eq!(value, "[1:2:3]");
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_quoted_value() {
+        let input = "cookie_name1=\"cookie_value1\"; cookie_name2=cookie_value2";
+
+        let expected_pair_1 = CookiePair {
+            name: String::from("cookie_name1"),
+            value: String::from("cookie_value1"),
+            quoted: true,
+        };
+        let expected_pair_2 = CookiePair {
+            name: String::from("cookie_name2"),
+            value: String::from("cookie_value2"),
+            quoted: false,
+        };
+
+        let actual = parse_cookie_string(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.len(), 2);
+                assert_eq!(result[0], expected_pair_1);
+                assert_eq!(result[1], expected_pair_2);
+                assert_eq!(result[0].raw_value(), "\"cookie_value1\"");
+                assert_eq!(result[1].raw_value(), "cookie_value2");
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_quoted_value_with_cookie_octets() {
+        let input = "cookie_name=\"a/b==\"; Path=/";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.pair.value, "a/b==");
+                assert!(result.pair.quoted);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_unterminated_quoted_value() {
+        let input = "cookie_name=\"cookie_value";
+
+        let actual = parse_cookie_string(input);
+
+        match actual {
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(_)) => assert!(true),
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_domain_host_names() {
+        let cases = [
+            ("my-site.com", "my-site.com"),
+            (".example.com", "example.com"),
+            ("xn--bcher-kva.example", "xn--bcher-kva.example"),
+            ("bücher.example", "bücher.example"),
+            ("Sub.Example.COM", "sub.example.com"),
+        ];
+
+        for (domain, expected) in cases {
+            let input = format!("cookie_name=cookie_value; Domain={}", domain);
+
+            let actual = parse_set_cookie(&input);
+
+            match actual {
+                Ok(result) => {
+                    assert_eq!(
+                        result.domain,
+                        Some(CookieDomain::Host(String::from(expected)))
+                    );
+                }
+                Err(_) => assert!(false),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_domain_ip_literals() {
+        let ipv4 = parse_set_cookie("cookie_name=cookie_value; Domain=127.0.0.1");
+        let ipv6 = parse_set_cookie("cookie_name=cookie_value; Domain=[::1]");
+
+        match (ipv4, ipv6) {
+            (Ok(ipv4), Ok(ipv6)) => {
+                assert_eq!(
+                    ipv4.domain,
+                    Some(CookieDomain::Ip(IpAddr::V4(Ipv4Addr::LOCALHOST)))
+                );
+                assert_eq!(
+                    ipv6.domain,
+                    Some(CookieDomain::Ip(IpAddr::V6(Ipv6Addr::LOCALHOST)))
+                );
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_domain_invalid() {
+        for input in [
+            "cookie_name=cookie_value; Domain=exa mple.com",
+            "cookie_name=cookie_value; Domain=example..com",
+            "cookie_name=cookie_value; Domain=[::1",
+        ] {
+            let actual = parse_set_cookie(input);
+
+            match actual {
+                Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(_)) => assert!(true),
+                _ => assert!(false),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_domain_invalid_ipv6_literal() {
+        let input = "cookie_name=cookie_value; Domain=[1:2:3]";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Err(cookie_parser::CookieParseError::ErrorInvalidAttributeValue { name, value }) => {
+                assert_eq!(name, "Domain");
+                assert_eq!(value, "[1:2:3]");
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_error_position_invalid_value() {
+        let input = "cookie_name1=cookie_value1; cookie_name2=,";
+
+        let actual = parse_cookie_string(input);
+
+        let expected = SyntaxError {
+            offset: 41,
+            line: 1,
+            column: 42,
+            snippet: String::from(","),
+            expected: vec![
+                String::from("'; ' or end of input"),
+                String::from("cookie-value"),
+            ],
+            rules: vec![String::from("EOI"), String::from("cookie_value")],
+        };
+
+        match actual {
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(error)) => {
+                assert_eq!(error, expected);
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_error_position_missing_space() {
+        let input = "cookie_name1=cookie_value1;cookie_name2=cookie_value_2";
+
+        let actual = parse_cookie_string(input);
+
+        let expected = SyntaxError {
+            offset: 26,
+            line: 1,
+            column: 27,
+            snippet: String::from(";cookie_name2=cookie_value_2"),
+            expected: vec![String::from("'; ' or end of input")],
+            rules: vec![String::from("EOI")],
+        };
+
+        match actual {
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(error)) => {
+                assert_eq!(error, expected);
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_error_position_invalid_attribute_value() {
+        let input = "cookie_name=cookie_value; Max-Age=soon; Secure";
+
+        let actual = parse_set_cookie(input);
+
+        match actual {
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(error)) => {
+                assert_eq!(error.offset, 34);
+                assert_eq!(error.column, 35);
+                assert_eq!(error.snippet, "soon");
+                assert_eq!(error.expected, vec![String::from("Max-Age seconds")]);
+                assert_eq!(
+                    error.to_string(),
+                    "expected Max-Age seconds at line 1, column 35, found 'soon'"
+                );
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_error_position_multiline() {
+        let input = "cookie_name=cookie_value\n";
+
+        let actual = parse_cookie_string(input);
+
+        match actual {
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(error)) => {
+                assert_eq!(error.offset, 24);
+                assert_eq!(error.line, 1);
+                assert_eq!(error.column, 25);
+                assert_eq!(error.snippet, "");
+            }
+            _ => assert!(false),
+        }
+    }
 }
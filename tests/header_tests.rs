@@ -0,0 +1,86 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use cookie_parser::{CookieHeader, CookiePair, DuplicatePolicy};
+
+    fn pair(name: &str, value: &str) -> CookiePair {
+        CookiePair {
+            name: String::from(name),
+            value: String::from(value),
+            quoted: false,
+        }
+    }
+
+    #[test]
+    fn test_cookie_header_get() {
+        let input = "theme=dark; session=abc123; lang=en";
+
+        match CookieHeader::parse(input) {
+            Ok(header) => {
+                assert_eq!(header.get("session"), Some(&pair("session", "abc123")));
+                assert_eq!(header.get("Session"), None);
+                assert!(header.contains("lang"));
+                assert!(!header.contains("missing"));
+                assert_eq!(header.len(), 3);
+                assert_eq!(
+                    header
+                        .iter()
+                        .map(|pair| pair.name.as_str())
+                        .collect::<Vec<_>>(),
+                    vec!["theme", "session", "lang"]
+                );
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_cookie_header_duplicates_first_and_last_wins() {
+        let input = "session=new; theme=dark; session=old";
+
+        match CookieHeader::parse(input) {
+            Ok(header) => {
+                assert_eq!(header.get("session"), Some(&pair("session", "new")));
+                assert_eq!(
+                    header.get_all("session").collect::<Vec<_>>(),
+                    vec![&pair("session", "new"), &pair("session", "old")]
+                );
+            }
+            Err(_) => assert!(false),
+        }
+
+        match CookieHeader::parse_with_policy(input, DuplicatePolicy::LastWins) {
+            Ok(header) => assert_eq!(header.get("session"), Some(&pair("session", "old"))),
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_cookie_header_duplicates_error() {
+        let input = "session=new; theme=dark; session=old";
+
+        match CookieHeader::parse_with_policy(input, DuplicatePolicy::Error) {
+            Ok(_) => assert!(false),
+            Err(cookie_parser::CookieParseError::ErrorDuplicateCookieName { name }) => {
+                assert_eq!(name, "session")
+            }
+            Err(_) => assert!(false),
+        }
+
+        let header = CookieHeader::parse_with_policy("a=1; b=2", DuplicatePolicy::Error).unwrap();
+
+        assert_eq!(header.get("b"), Some(&pair("b", "2")));
+    }
+
+    #[test]
+    fn test_cookie_header_invalid_syntax() {
+        let input = "a=1;b=2";
+
+        match CookieHeader::parse(input) {
+            Ok(_) => assert!(false),
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(_)) => assert!(true),
+            Err(_) => assert!(false),
+        }
+    }
+}
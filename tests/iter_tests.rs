@@ -0,0 +1,107 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use cookie_parser::{parse_cookie_string, parse_cookie_string_iter, CookiePairRef};
+
+    #[test]
+    fn test_parse_cookie_string_iter_matches_parse_cookie_string() {
+        let input =
+            "cookie_name1=cookie_value1; cookie_name2=\"cookie_value2\"; cookie_name3=abc==";
+
+        let actual: Result<Vec<_>, _> = parse_cookie_string_iter(input)
+            .map(|pair| pair.map(CookiePairRef::into_owned))
+            .collect();
+
+        match actual {
+            Ok(result) => assert_eq!(result, parse_cookie_string(input).unwrap()),
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_string_iter_early_exit() {
+        let input = "theme=dark; session=abc123; broken pair";
+
+        let actual = parse_cookie_string_iter(input)
+            .filter_map(Result::ok)
+            .find(|pair| pair.name == "session");
+
+        assert_eq!(
+            actual,
+            Some(CookiePairRef {
+                name: "session",
+                value: "abc123",
+                quoted: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cookie_string_iter_skips_malformed_pairs() {
+        let input = "a=1; b=\"2; c=3;d=4; e=5";
+
+        let results: Vec<_> = parse_cookie_string_iter(input).collect();
+
+        assert_eq!(results.len(), 5);
+        assert_eq!(
+            results[0].as_ref().ok(),
+            Some(&CookiePairRef {
+                name: "a",
+                value: "1",
+                quoted: false,
+            })
+        );
+
+        match &results[1] {
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(error)) => {
+                assert_eq!(error.offset, 7);
+            }
+            _ => assert!(false),
+        }
+
+        assert_eq!(
+            results[2].as_ref().ok(),
+            Some(&CookiePairRef {
+                name: "c",
+                value: "3",
+                quoted: false,
+            })
+        );
+
+        match &results[3] {
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(error)) => {
+                assert_eq!(error.offset, 15);
+                assert_eq!(error.expected, vec![String::from("' ' after ';'")]);
+            }
+            _ => assert!(false),
+        }
+
+        assert_eq!(
+            results[4].as_ref().ok(),
+            Some(&CookiePairRef {
+                name: "e",
+                value: "5",
+                quoted: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cookie_string_iter_trailing_delimiter() {
+        let input = "a=1; ";
+
+        let mut iter = parse_cookie_string_iter(input);
+
+        assert!(iter.next().is_some_and(|pair| pair.is_ok()));
+
+        match iter.next() {
+            Some(Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(error))) => {
+                assert_eq!(error.offset, 5);
+            }
+            _ => assert!(false),
+        }
+
+        assert!(iter.next().is_none());
+    }
+}
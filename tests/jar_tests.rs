@@ -0,0 +1,298 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use cookie_parser::{
+        parse_cookie_string, parse_set_cookie, parse_set_cookie_lenient, ApiContext, CookieJar,
+        CookieJarError, SetCookie, Url,
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap()
+    }
+
+    fn insert(jar: &mut CookieJar, input: &str, url: &str, now: DateTime<Utc>) {
+        let url = Url::parse(url).unwrap();
+
+        match jar.insert(
+            parse_set_cookie(input).unwrap(),
+            &url,
+            ApiContext::Http,
+            now,
+        ) {
+            Ok(()) => assert!(true),
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_insert_host_only_and_domain_cookies() {
+        let mut jar = CookieJar::new();
+
+        insert(
+            &mut jar,
+            "a=1; Max-Age=60",
+            "https://www.example.com/docs/index.html",
+            now(),
+        );
+        insert(
+            &mut jar,
+            "b=2; Domain=.Example.COM; Path=/; Secure; HttpOnly",
+            "https://www.example.com/",
+            now(),
+        );
+        insert(
+            &mut jar,
+            "c=3; Path=relative",
+            "https://www.example.com/docs/",
+            now(),
+        );
+
+        let a = jar.get("a", "www.example.com", "/docs").unwrap();
+        assert!(a.host_only);
+        assert_eq!(a.expiry_time, Some(now() + Duration::seconds(60)));
+        assert_eq!(a.creation_time, now());
+        assert_eq!(a.last_access_time, now());
+
+        let b = jar.get("b", "example.com", "/").unwrap();
+        assert!(!b.host_only);
+        assert!(!b.is_persistent());
+        assert!(b.secure_only && b.http_only);
+
+        assert!(jar.get("c", "www.example.com", "/docs").is_some());
+        assert_eq!(jar.len(), 3);
+    }
+
+    #[test]
+    fn test_insert_rejects_domain_mismatch() {
+        let mut jar = CookieJar::new();
+        let url = Url::parse("https://www.example.com/").unwrap();
+
+        for input in [
+            "a=1; Domain=other.com",
+            "a=1; Domain=sub.www.example.com",
+            "a=1; Domain=ample.com",
+        ] {
+            match jar.insert(
+                parse_set_cookie(input).unwrap(),
+                &url,
+                ApiContext::Http,
+                now(),
+            ) {
+                Ok(()) => assert!(false),
+                Err(CookieJarError::ErrorDomainMismatch { host, .. }) => {
+                    assert_eq!(host, "www.example.com")
+                }
+                Err(_) => assert!(false),
+            }
+        }
+
+        // Cookies for an IP address cannot be shared with other hosts.
+        let url = Url::parse("http://127.0.0.1/").unwrap();
+
+        assert!(jar
+            .insert(
+                parse_set_cookie("a=1; Domain=0.0.1").unwrap(),
+                &url,
+                ApiContext::Http,
+                now()
+            )
+            .is_err());
+        assert!(jar
+            .insert(
+                parse_set_cookie("a=1; Domain=127.0.0.1").unwrap(),
+                &url,
+                ApiContext::Http,
+                now()
+            )
+            .is_ok());
+        assert_eq!(jar.len(), 1);
+    }
+
+    #[test]
+    fn test_insert_replaces_and_removes() {
+        let mut jar = CookieJar::new();
+        let later = now() + Duration::seconds(10);
+
+        insert(&mut jar, "id=old; Path=/", "https://example.com/", now());
+        insert(
+            &mut jar,
+            "id=new; Path=/; Max-Age=60",
+            "https://example.com/",
+            later,
+        );
+        insert(
+            &mut jar,
+            "id=other; Path=/docs",
+            "https://example.com/",
+            later,
+        );
+
+        let id = jar.get("id", "example.com", "/").unwrap();
+        assert_eq!(id.pair.value, "new");
+        assert_eq!(id.creation_time, now());
+        assert_eq!(id.last_access_time, later);
+        assert_eq!(jar.len(), 2);
+
+        let removal = SetCookie::removal("id", Some("/"), None);
+        let url = Url::parse("https://example.com/").unwrap();
+        jar.insert(removal, &url, ApiContext::Http, later).unwrap();
+
+        assert!(jar.get("id", "example.com", "/").is_none());
+        assert_eq!(jar.len(), 1);
+
+        jar.end_session();
+        assert!(jar.is_empty());
+    }
+
+    #[test]
+    fn test_insert_http_only_from_non_http_api() {
+        let mut jar = CookieJar::new();
+        let url = Url::parse("https://example.com/").unwrap();
+
+        match jar.insert(
+            parse_set_cookie("a=1; HttpOnly").unwrap(),
+            &url,
+            ApiContext::NonHttp,
+            now(),
+        ) {
+            Err(CookieJarError::ErrorHttpOnlyFromNonHttpApi(name)) => assert_eq!(name, "a"),
+            _ => assert!(false),
+        }
+
+        insert(
+            &mut jar,
+            "a=1; HttpOnly; Expires=Wed, 21 Oct 2015 08:28:00 GMT",
+            "https://example.com/",
+            now(),
+        );
+
+        match jar.insert(
+            parse_set_cookie("a=2").unwrap(),
+            &url,
+            ApiContext::NonHttp,
+            now(),
+        ) {
+            Err(CookieJarError::ErrorReplacesHttpOnly(name)) => assert_eq!(name, "a"),
+            _ => assert!(false),
+        }
+
+        assert_eq!(jar.get("a", "example.com", "/").unwrap().pair.value, "1");
+
+        jar.remove_expired(now() + Duration::hours(1));
+        assert!(jar.is_empty());
+    }
+
+    #[test]
+    fn test_cookie_header_selection_and_order() {
+        let mut jar = CookieJar::new();
+        let later = now() + Duration::seconds(10);
+
+        insert(&mut jar, "b=2; Path=/", "https://www.example.com/", later);
+        insert(&mut jar, "a=1; Path=/", "https://www.example.com/", now());
+        insert(
+            &mut jar,
+            "a=3; Path=/docs",
+            "https://www.example.com/",
+            later,
+        );
+        insert(
+            &mut jar,
+            "shared=4; Domain=example.com; Path=/",
+            "https://www.example.com/",
+            now(),
+        );
+        insert(
+            &mut jar,
+            "secure=5; Path=/; Secure",
+            "https://www.example.com/",
+            now(),
+        );
+        insert(
+            &mut jar,
+            "http=6; Path=/; HttpOnly",
+            "https://www.example.com/",
+            now(),
+        );
+        insert(
+            &mut jar,
+            "empty=; Path=/",
+            "https://www.example.com/",
+            now(),
+        );
+        insert(
+            &mut jar,
+            "other=7; Path=/docsearch",
+            "https://www.example.com/",
+            now(),
+        );
+
+        let url = Url::parse("https://www.example.com/docs/index.html").unwrap();
+        let header = jar.cookie_header(&url, ApiContext::Http, later).unwrap();
+
+        assert_eq!(
+            header.to_string(),
+            "a=3; a=1; shared=4; secure=5; http=6; empty=; b=2"
+        );
+        assert_eq!(header.get("a").unwrap().value, "3");
+
+        match parse_cookie_string(&header.to_string()) {
+            Ok(result) => assert_eq!(result, header.into_pairs()),
+            Err(_) => assert!(false),
+        }
+
+        let url = Url::parse("http://sub.example.com/docs").unwrap();
+        let header = jar.cookie_header(&url, ApiContext::NonHttp, later).unwrap();
+        assert_eq!(header.to_string(), "shared=4");
+
+        let url = Url::parse("http://example.org/").unwrap();
+        assert!(jar.cookie_header(&url, ApiContext::Http, later).is_none());
+    }
+
+    #[test]
+    fn test_cookies_for_sends_lenient_cookies() {
+        let mut jar = CookieJar::new();
+        let url = Url::parse("https://example.com/").unwrap();
+
+        jar.insert(
+            parse_set_cookie_lenient("spaced = a b ; Path=/").unwrap(),
+            &url,
+            ApiContext::Http,
+            now(),
+        )
+        .unwrap();
+
+        let cookies = jar.cookies_for(&url, ApiContext::Http, now());
+
+        assert_eq!(cookies.len(), 1);
+        assert_eq!(cookies[0].value, "a b");
+    }
+
+    #[test]
+    fn test_cookies_for_updates_last_access_time_and_removes_expired() {
+        let mut jar = CookieJar::new();
+        let later = now() + Duration::seconds(90);
+
+        insert(&mut jar, "a=1; Max-Age=60", "https://example.com/", now());
+        insert(&mut jar, "b=2", "https://example.com/", now());
+        insert(&mut jar, "c=3; Path=/docs", "https://example.com/", now());
+
+        let url = Url::parse("https://example.com/").unwrap();
+        let cookies = jar.cookies_for(&url, ApiContext::Http, later);
+
+        assert_eq!(cookies.len(), 1);
+        assert_eq!(cookies[0].name, "b");
+        assert_eq!(jar.len(), 2);
+        assert_eq!(
+            jar.get("b", "example.com", "/").unwrap().last_access_time,
+            later
+        );
+        assert_eq!(
+            jar.get("c", "example.com", "/docs")
+                .unwrap()
+                .last_access_time,
+            now()
+        );
+    }
+}
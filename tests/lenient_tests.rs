@@ -0,0 +1,183 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use cookie_parser::{
+        parse_cookie_string_lenient, parse_set_cookie_lenient, CookieDomain, CookiePair, SameSite,
+        SetCookie,
+    };
+
+    #[test]
+    fn test_parse_cookie_string_lenient_no_space_between_cookies() {
+        let input = "cookie_name1=cookie_value1;cookie_name2=cookie_value2";
+
+        let expected_pair_1 = CookiePair {
+            name: String::from("cookie_name1"),
+            value: String::from("cookie_value1"),
+            quoted: false,
+        };
+        let expected_pair_2 = CookiePair {
+            name: String::from("cookie_name2"),
+            value: String::from("cookie_value2"),
+            quoted: false,
+        };
+
+        let actual = parse_cookie_string_lenient(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.len(), 2);
+                assert_eq!(result[0], expected_pair_1);
+                assert_eq!(result[1], expected_pair_2);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_string_lenient_extra_whitespace_and_trailing_delimiter() {
+        let input = "  cookie_name1=cookie_value1;   cookie_name2 = cookie_value2 ; ";
+
+        let actual = parse_cookie_string_lenient(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.len(), 2);
+                assert_eq!(result[0].name, "cookie_name1");
+                assert_eq!(result[1].name, "cookie_name2");
+                assert_eq!(result[1].value, "cookie_value2");
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_string_lenient_skips_malformed_segments() {
+        let input = "cookie_name1=cookie_value1; garbage; =no_name; cookie_name2=\"quoted\"";
+
+        let actual = parse_cookie_string_lenient(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.len(), 2);
+                assert_eq!(result[1].value, "quoted");
+                assert!(result[1].quoted);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_cookie_string_lenient_empty() {
+        let actual = parse_cookie_string_lenient(" ; ");
+
+        match actual {
+            Err(cookie_parser::CookieParseError::ErrorCookieStringEmpty) => assert!(true),
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_lenient_mixed() {
+        let input = " cookie_name = cookie_value ;Max-Age=60;  path=/path ;SECURE;httponly; SameSite = lax; Expires=Wed, 21 Oct 2015 07:28:00 GMT; MyKey=MyValue;";
+
+        let actual = parse_set_cookie_lenient(input);
+
+        let expected = SetCookie {
+            pair: CookiePair {
+                name: String::from("cookie_name"),
+                value: String::from("cookie_value"),
+                quoted: false,
+            },
+            domain: None,
+            max_age: Some(Duration::seconds(60)),
+            path: Some(String::from("/path")),
+            expires: Some(Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap()),
+            same_site: Some(SameSite::Lax),
+            partitioned: false,
+            priority: None,
+            http_only: true,
+            secure: true,
+            extensions: vec![String::from("MyKey=MyValue")],
+        };
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result, expected);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_lenient_ignores_invalid_attribute_values() {
+        let input = "cookie_name=cookie_value; Max-Age=soon; Expires=tomorrow; Path=relative; Priority=Urgent";
+
+        let actual = parse_set_cookie_lenient(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(result.max_age, None);
+                assert_eq!(result.expires, None);
+                assert_eq!(result.path, None);
+                assert_eq!(result.priority, None);
+                assert!(result.extensions.is_empty());
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_lenient_domain_leading_dot() {
+        let input = "cookie_name=cookie_value; Domain=.Example.COM";
+
+        let actual = parse_set_cookie_lenient(input);
+
+        match actual {
+            Ok(result) => {
+                assert_eq!(
+                    result.domain,
+                    Some(CookieDomain::Host(String::from("example.com")))
+                );
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_lenient_missing_equals() {
+        let actual = parse_set_cookie_lenient("cookie_name; Secure");
+
+        match actual {
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(error)) => {
+                assert_eq!(error.offset, 11);
+                assert_eq!(error.expected, vec![String::from("'='")]);
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_lenient_empty_name() {
+        let actual = parse_set_cookie_lenient(" =cookie_value; Secure");
+
+        match actual {
+            Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(_)) => assert!(true),
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_lenient_empty_name_non_ascii_value() {
+        for input in ["=é  ", "  =é\t"] {
+            match parse_set_cookie_lenient(input) {
+                Err(cookie_parser::CookieParseError::ErrorCookieStringSyntax(error)) => {
+                    assert_eq!(error.offset, input.len() - input.trim_start().len());
+                    assert_eq!(error.expected, vec![String::from("cookie-name")]);
+                }
+                _ => assert!(false),
+            }
+        }
+    }
+}
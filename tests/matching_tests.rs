@@ -0,0 +1,78 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use cookie_parser::{
+        canonicalize_host, default_path, domain_match, parse_set_cookie, path_match,
+    };
+
+    #[test]
+    fn test_domain_match() {
+        assert!(domain_match("example.com", "example.com"));
+        assert!(domain_match("www.example.com", "example.com"));
+        assert!(domain_match("a.b.example.com", "example.com"));
+        assert!(domain_match("WWW.Example.COM", "example.com"));
+        assert!(domain_match("shop.bücher.example", "xn--bcher-kva.example"));
+        assert!(domain_match("127.0.0.1", "127.0.0.1"));
+        assert!(domain_match("[::1]", "[0:0::1]"));
+
+        assert!(!domain_match("example.com", "www.example.com"));
+        assert!(!domain_match("badexample.com", "example.com"));
+        assert!(!domain_match("example.com", ".example.com"));
+        assert!(!domain_match("example.com.evil", "example.com"));
+        assert!(!domain_match("127.0.0.1", "0.0.1"));
+        assert!(!domain_match("1.2.3.4", "3.4"));
+        assert!(!domain_match("exa mple.com", "mple.com"));
+    }
+
+    #[test]
+    fn test_path_match() {
+        assert!(path_match("/", "/"));
+        assert!(path_match("/docs", "/"));
+        assert!(path_match("/docs", "/docs"));
+        assert!(path_match("/docs/", "/docs"));
+        assert!(path_match("/docs/web", "/docs"));
+        assert!(path_match("/docs/web", "/docs/"));
+
+        assert!(!path_match("/docsearch", "/docs"));
+        assert!(!path_match("/docs", "/docs/"));
+        assert!(!path_match("/Docs", "/docs"));
+        assert!(!path_match("/", "/docs"));
+    }
+
+    #[test]
+    fn test_default_path_and_canonicalize_host() {
+        assert_eq!(default_path(""), "/");
+        assert_eq!(default_path("docs"), "/");
+        assert_eq!(default_path("/"), "/");
+        assert_eq!(default_path("/index.html"), "/");
+        assert_eq!(default_path("/docs/"), "/docs");
+        assert_eq!(default_path("/docs/web/index.html"), "/docs/web");
+
+        assert_eq!(
+            canonicalize_host("WWW.Bücher.Example").as_deref(),
+            Some("www.xn--bcher-kva.example")
+        );
+        assert_eq!(canonicalize_host("[0:0::1]").as_deref(), Some("[::1]"));
+        assert_eq!(canonicalize_host("127.0.0.1").as_deref(), Some("127.0.0.1"));
+        assert_eq!(canonicalize_host(""), None);
+        assert_eq!(canonicalize_host("exa mple.com"), None);
+        assert_eq!(canonicalize_host("[zz]"), None);
+    }
+
+    #[test]
+    fn test_set_cookie_domain_and_path_matches() {
+        let set_cookie = parse_set_cookie("id=a3fWa; Domain=.Bücher.example; Path=/docs").unwrap();
+
+        assert!(set_cookie.domain_matches("www.xn--bcher-kva.example"));
+        assert!(set_cookie.domain_matches("Bücher.example"));
+        assert!(!set_cookie.domain_matches("example"));
+        assert!(set_cookie.path_matches("/docs/web"));
+        assert!(!set_cookie.path_matches("/docsearch"));
+
+        let set_cookie = parse_set_cookie("id=a3fWa; Path=docs").unwrap();
+
+        assert!(!set_cookie.domain_matches("example.com"));
+        assert!(!set_cookie.path_matches("/docs"));
+    }
+}
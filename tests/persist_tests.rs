@@ -0,0 +1,206 @@
+#![cfg(feature = "persist")]
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf, process, thread};
+
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use cookie_parser::{
+        parse_set_cookie, parse_set_cookie_lenient, ApiContext, CookieJar, CookieJarFileError,
+        JarFormat, Url,
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap()
+    }
+
+    fn jar() -> CookieJar {
+        let url = Url::parse("https://www.example.com/docs/index.html").unwrap();
+        let mut jar = CookieJar::new();
+
+        for input in [
+            "id=\"a3fWa\"; Max-Age=3600; Secure; HttpOnly; SameSite=Lax",
+            "theme=dark; Domain=example.com; Path=/; Expires=Wed, 21 Oct 2025 07:28:00 GMT",
+            "lang=en; Max-Age=9223372036854775807; SameSite=Bogus",
+            "short=1; Max-Age=60",
+            "session=1",
+        ] {
+            jar.insert(
+                parse_set_cookie(input).unwrap(),
+                &url,
+                ApiContext::Http,
+                now(),
+            )
+            .unwrap();
+        }
+
+        jar
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("cookie_parser_{}_{}", name, process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_to_bytes_and_from_bytes() {
+        let jar = jar();
+        let later = now() + Duration::seconds(120);
+
+        for format in [JarFormat::Json, JarFormat::Binary] {
+            let bytes = jar.to_bytes(format, now());
+            let loaded = CookieJar::from_bytes(&bytes, format, now()).unwrap();
+
+            assert_eq!(loaded.len(), 4);
+            assert!(jar.get("session", "www.example.com", "/docs").is_some());
+            assert!(loaded.get("session", "www.example.com", "/docs").is_none());
+
+            for cookie in &loaded {
+                assert_eq!(
+                    Some(cookie),
+                    jar.get(&cookie.pair.name, &cookie.domain, &cookie.path)
+                );
+            }
+
+            // Cookies that expired since the jar was saved are dropped when it is loaded.
+            let loaded = CookieJar::from_bytes(&bytes, format, later).unwrap();
+            assert_eq!(loaded.len(), 3);
+            assert!(loaded.get("short", "www.example.com", "/docs").is_none());
+        }
+
+        assert!(
+            jar.to_bytes(JarFormat::Binary, now()).len()
+                < jar.to_bytes(JarFormat::Json, now()).len()
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_invalid() {
+        let bytes = jar().to_bytes(JarFormat::Binary, now());
+
+        for input in [
+            &b"NOPE\x01\x00\x00\x00\x00"[..],
+            &b"CKJR\x02\x00\x00\x00\x00"[..],
+            &bytes[..bytes.len() - 1],
+            &[&bytes[..], b"x"].concat(),
+        ] {
+            match CookieJar::from_bytes(input, JarFormat::Binary, now()) {
+                Ok(_) => assert!(false),
+                Err(CookieJarFileError::ErrorBinary(_)) => assert!(true),
+                Err(_) => assert!(false),
+            }
+        }
+
+        match CookieJar::from_bytes(b"{\"cookies\": 1}", JarFormat::Json, now()) {
+            Ok(_) => assert!(false),
+            Err(CookieJarFileError::ErrorJson(_)) => assert!(true),
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = temp_dir("save");
+        let jar = jar();
+
+        for (format, file_name) in [
+            (JarFormat::Json, "jar.json"),
+            (JarFormat::Binary, "jar.bin"),
+        ] {
+            let path = dir.join(file_name);
+
+            assert!(CookieJar::load(&path, format, now()).unwrap().is_empty());
+
+            jar.save(&path, format, now()).unwrap();
+            jar.save(&path, format, now()).unwrap();
+
+            let loaded = CookieJar::load(&path, format, now()).unwrap();
+            assert_eq!(
+                loaded,
+                CookieJar::from_bytes(&fs::read(&path).unwrap(), format, now()).unwrap()
+            );
+            assert_eq!(loaded.len(), 4);
+        }
+
+        let mut file_names: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        file_names.sort();
+
+        assert_eq!(
+            file_names,
+            ["jar.bin", "jar.bin.lock", "jar.json", "jar.json.lock"]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_save_and_load_lenient_cookies() {
+        let dir = temp_dir("lenient");
+        let url = Url::parse("https://www.example.com/").unwrap();
+        let mut jar = CookieJar::new();
+
+        for input in ["a=b\u{1}c; Max-Age=1000", "q=\"x\"y\"; Max-Age=60"] {
+            jar.insert(
+                parse_set_cookie_lenient(input).unwrap(),
+                &url,
+                ApiContext::Http,
+                now(),
+            )
+            .unwrap();
+        }
+
+        for (format, file_name) in [
+            (JarFormat::Json, "jar.json"),
+            (JarFormat::Binary, "jar.bin"),
+        ] {
+            let path = dir.join(file_name);
+
+            jar.save(&path, format, now()).unwrap();
+
+            match CookieJar::load(&path, format, now()) {
+                Ok(loaded) => assert_eq!(loaded, jar),
+                Err(_) => assert!(false),
+            }
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_update_concurrently() {
+        let dir = temp_dir("update");
+        let path = dir.join("jar.bin");
+
+        let handles: Vec<_> = (0..8)
+            .map(|index| {
+                let path = path.clone();
+
+                thread::spawn(move || {
+                    let url = Url::parse("https://example.com/").unwrap();
+                    let set_cookie =
+                        parse_set_cookie(&format!("c{}=1; Max-Age=60", index)).unwrap();
+
+                    CookieJar::update(&path, JarFormat::Binary, now(), |jar| {
+                        jar.insert(set_cookie, &url, ApiContext::Http, now())
+                    })
+                    .unwrap()
+                    .unwrap();
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let jar = CookieJar::load(&path, JarFormat::Binary, now()).unwrap();
+        assert_eq!(jar.len(), 8);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "psl")]
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use chrono::{TimeZone, Utc};
+    use cookie_parser::{
+        parse_set_cookie, ApiContext, CookieJar, CookieJarError, PublicSuffixCheck,
+        PublicSuffixList, Url,
+    };
+
+    #[test]
+    fn test_public_suffix_bundled() {
+        let list = PublicSuffixList::bundled();
+
+        let cases = [
+            ("example.com", Some("com")),
+            ("WWW.Example.co.uk", Some("co.uk")),
+            ("user.github.io", Some("github.io")),
+            ("a.b.example.kawasaki.jp", Some("example.kawasaki.jp")),
+            ("city.kawasaki.jp", Some("kawasaki.jp")),
+            ("www.ck", Some("ck")),
+            ("shop.例え.公司.cn", Some("xn--55qx5d.cn")),
+            ("example.unlisted", Some("unlisted")),
+            ("127.0.0.1", None),
+            ("[::1]", None),
+            ("exa mple.com", None),
+        ];
+
+        for (domain, expected) in cases {
+            assert_eq!(
+                list.public_suffix(domain).as_deref(),
+                expected,
+                "domain: {}",
+                domain
+            );
+        }
+
+        assert!(list.is_public_suffix("CO.UK"));
+        assert!(list.is_public_suffix("cloudfront.net"));
+        assert!(list.is_public_suffix("pvt.k12.ma.us"));
+        assert!(list.is_public_suffix("anything.ck"));
+        assert!(!list.is_public_suffix("www.ck"));
+        assert!(!list.is_public_suffix("example.com"));
+    }
+
+    #[test]
+    fn test_public_suffix_list_from_file() {
+        let path = env::temp_dir().join(format!("cookie_parser_psl_{}.dat", process::id()));
+        let input = "// comment\n\nexample\nhosting.example trailing text\n*.wild\n!not.wild\n";
+        fs::write(&path, input).unwrap();
+
+        let list = PublicSuffixList::from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            list.public_suffix("a.hosting.example").as_deref(),
+            Some("hosting.example")
+        );
+        assert_eq!(list.public_suffix("a.b.wild").as_deref(), Some("b.wild"));
+        assert_eq!(list.public_suffix("a.not.wild").as_deref(), Some("wild"));
+        assert_eq!(list.public_suffix("example.com").as_deref(), Some("com"));
+        assert_eq!(list, PublicSuffixList::parse(input));
+
+        assert!(PublicSuffixList::from_file(&path).is_err());
+    }
+
+    #[test]
+    fn test_check_public_suffix() {
+        let list = PublicSuffixList::bundled();
+
+        let cases = [
+            ("id=1", "www.example.com", PublicSuffixCheck::Allowed),
+            (
+                "id=1; Domain=example.com",
+                "www.example.com",
+                PublicSuffixCheck::Allowed,
+            ),
+            (
+                "id=1; Domain=com",
+                "www.example.com",
+                PublicSuffixCheck::Rejected,
+            ),
+            (
+                "id=1; Domain=.co.uk",
+                "example.co.uk",
+                PublicSuffixCheck::Rejected,
+            ),
+            (
+                "id=1; Domain=github.io",
+                "user.github.io",
+                PublicSuffixCheck::Rejected,
+            ),
+            (
+                "id=1; Domain=github.io",
+                "GitHub.io",
+                PublicSuffixCheck::HostOnly,
+            ),
+            (
+                "id=1; Domain=127.0.0.1",
+                "127.0.0.1",
+                PublicSuffixCheck::Allowed,
+            ),
+        ];
+
+        for (input, host, expected) in cases {
+            let set_cookie = parse_set_cookie(input).unwrap();
+
+            assert_eq!(
+                set_cookie.check_public_suffix(host, &list),
+                expected,
+                "input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_jar_rejects_public_suffix_domain() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+        let mut jar = CookieJar::new().with_public_suffix_list(PublicSuffixList::bundled());
+
+        let url = Url::parse("https://www.example.co.uk/").unwrap();
+
+        match jar.insert(
+            parse_set_cookie("id=1; Domain=co.uk").unwrap(),
+            &url,
+            ApiContext::Http,
+            now,
+        ) {
+            Ok(()) => assert!(false),
+            Err(CookieJarError::ErrorPublicSuffixDomain { domain, host }) => {
+                assert_eq!(domain, "co.uk");
+                assert_eq!(host, "www.example.co.uk");
+            }
+            Err(_) => assert!(false),
+        }
+
+        jar.insert(
+            parse_set_cookie("id=2; Domain=example.co.uk").unwrap(),
+            &url,
+            ApiContext::Http,
+            now,
+        )
+        .unwrap();
+
+        let url = Url::parse("https://github.io/").unwrap();
+        jar.insert(
+            parse_set_cookie("id=3; Domain=github.io").unwrap(),
+            &url,
+            ApiContext::Http,
+            now,
+        )
+        .unwrap();
+
+        assert!(!jar.get("id", "example.co.uk", "/").unwrap().host_only);
+        assert!(jar.get("id", "github.io", "/").unwrap().host_only);
+        assert_eq!(jar.len(), 2);
+
+        // Without a list the jar accepts any domain the request host domain-matches.
+        let mut jar = CookieJar::new();
+        let url = Url::parse("https://www.example.co.uk/").unwrap();
+
+        assert!(jar
+            .insert(
+                parse_set_cookie("id=1; Domain=co.uk").unwrap(),
+                &url,
+                ApiContext::Http,
+                now
+            )
+            .is_ok());
+    }
+}
@@ -0,0 +1,110 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use cookie_parser::{
+        parse_set_cookie, parse_set_cookie_recovering, CookieDomain, SetCookieProblem,
+    };
+
+    #[test]
+    fn test_parse_set_cookie_recovering_valid_input() {
+        let input = "cookie_name=cookie_value; Max-Age=60; Path=/; Secure; MyKey=MyValue";
+
+        let actual = parse_set_cookie_recovering(input);
+
+        match parse_set_cookie(input) {
+            Ok(expected) => {
+                assert_eq!(actual.set_cookie, expected);
+                assert!(actual.problems.is_empty());
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_recovering_collects_all_problems() {
+        let input = "cookie_name=cookie_value; Max-Age=soon; Domain=google.com; Expires=tomorrow;Secure; Path=/a; Path=/b";
+
+        let actual = parse_set_cookie_recovering(input);
+
+        assert_eq!(actual.problems.len(), 4);
+
+        match &actual.problems[0] {
+            SetCookieProblem::InvalidAttribute(error) => {
+                assert_eq!(error.offset, 34);
+                assert_eq!(error.expected, vec![String::from("Max-Age seconds")]);
+            }
+            _ => assert!(false),
+        }
+
+        assert_eq!(
+            actual.problems[1],
+            SetCookieProblem::InvalidAttributeValue {
+                name: String::from("Expires"),
+                value: String::from("tomorrow"),
+                offset: 59,
+            }
+        );
+
+        match &actual.problems[2] {
+            SetCookieProblem::InvalidAttribute(error) => {
+                assert_eq!(error.offset, 76);
+                assert_eq!(error.snippet, "Secure");
+            }
+            _ => assert!(false),
+        }
+
+        assert_eq!(
+            actual.problems[3],
+            SetCookieProblem::DuplicateAttribute {
+                name: String::from("Path"),
+                offset: 93,
+            }
+        );
+
+        assert_eq!(actual.set_cookie.max_age, None);
+        assert_eq!(actual.set_cookie.expires, None);
+        assert_eq!(
+            actual.set_cookie.domain,
+            Some(CookieDomain::Host(String::from("google.com")))
+        );
+        assert!(actual.set_cookie.secure);
+        assert_eq!(actual.set_cookie.path, Some(String::from("/b")));
+    }
+
+    #[test]
+    fn test_parse_set_cookie_recovering_invalid_pair() {
+        let input = "cookie_name=cookie value; Max-Age=60";
+
+        let actual = parse_set_cookie_recovering(input);
+
+        match &actual.problems[..] {
+            [SetCookieProblem::InvalidPair(error)] => {
+                assert_eq!(error.offset, 18);
+            }
+            _ => assert!(false),
+        }
+
+        assert_eq!(actual.set_cookie.pair.name, "cookie_name");
+        assert_eq!(actual.set_cookie.pair.value, "cookie value");
+        assert_eq!(actual.set_cookie.max_age, Some(Duration::seconds(60)));
+    }
+
+    #[test]
+    fn test_parse_set_cookie_recovering_trailing_delimiter() {
+        let input = "cookie_name=cookie_value; Secure; ";
+
+        let actual = parse_set_cookie_recovering(input);
+
+        match &actual.problems[..] {
+            [SetCookieProblem::InvalidAttribute(error)] => {
+                assert_eq!(error.offset, 34);
+                assert_eq!(error.expected, vec![String::from("cookie attribute")]);
+            }
+            _ => assert!(false),
+        }
+
+        assert!(actual.set_cookie.secure);
+    }
+}
@@ -0,0 +1,98 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use cookie_parser::{
+        parse_cookie_string, parse_set_cookie, CookieDomain, CookiePair, SameSite, SetCookie,
+    };
+
+    #[test]
+    fn test_removal_from_parsed_set_cookie() {
+        let input = "id=a3fWa; Max-Age=3600; Domain=example.com; Path=/docs; Secure; HttpOnly; SameSite=None; Partitioned; Priority=High; Foo=bar";
+
+        let removal = parse_set_cookie(input).unwrap().to_removal();
+
+        let expected = SetCookie {
+            pair: CookiePair {
+                name: String::from("id"),
+                value: String::new(),
+                quoted: false,
+            },
+            domain: Some(CookieDomain::Host(String::from("example.com"))),
+            max_age: Some(Duration::zero()),
+            path: Some(String::from("/docs")),
+            expires: Some(DateTime::UNIX_EPOCH),
+            same_site: Some(SameSite::None),
+            partitioned: true,
+            priority: None,
+            http_only: true,
+            secure: true,
+            extensions: vec![],
+        };
+
+        assert_eq!(removal, expected);
+        assert_eq!(
+            removal.to_string(),
+            "id=; Expires=Thu, 01 Jan 1970 00:00:00 GMT; Max-Age=0; Domain=example.com; Path=/docs; Secure; HttpOnly; SameSite=None; Partitioned"
+        );
+        assert!(removal.is_removal(Utc::now()));
+    }
+
+    #[test]
+    fn test_removal_header_parses_back() {
+        let removal = SetCookie::removal("session", Some("/"), None);
+
+        assert_eq!(
+            removal.to_string(),
+            "session=; Expires=Thu, 01 Jan 1970 00:00:00 GMT; Max-Age=0; Path=/"
+        );
+
+        match parse_set_cookie(&removal.to_string()) {
+            Ok(result) => assert_eq!(result, removal),
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_parse_set_cookie_empty_value() {
+        match parse_set_cookie("id=; Path=/") {
+            Ok(result) => {
+                assert_eq!(result.pair.value, "");
+                assert!(!result.pair.quoted);
+            }
+            Err(_) => assert!(false),
+        }
+
+        // Cookie headers carry empty values too.
+        match parse_cookie_string("id=") {
+            Ok(result) => assert_eq!(result[0].value, ""),
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_is_removal() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+        let is_removal = |input: &str| parse_set_cookie(input).unwrap().is_removal(now);
+
+        assert!(is_removal("id=; Max-Age=0"));
+        assert!(is_removal("id=a; Max-Age=-1"));
+        assert!(is_removal("id=a; Expires=Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert!(!is_removal("id=a"));
+        assert!(!is_removal("id=a; Max-Age=60"));
+        assert!(!is_removal(
+            "id=a; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Max-Age=60"
+        ));
+
+        assert!(!is_removal("id=a; Expires=Wed, 21 Oct 2015 07:28:01 GMT"));
+
+        let set_cookie = SetCookie::builder("id", "a")
+            .expires(now + Duration::seconds(1))
+            .build()
+            .unwrap();
+
+        assert!(!set_cookie.is_removal(now));
+        assert!(set_cookie.is_removal(now + Duration::seconds(1)));
+    }
+}
@@ -0,0 +1,163 @@
+#![cfg(feature = "serde")]
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use cookie_parser::{
+        parse_set_cookie, parse_set_cookie_borrowed, parse_set_cookie_lenient,
+        parse_set_cookie_recovering, CookieHeader, CookiePair, SetCookie, SetCookieRef,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_set_cookie_json_shape() {
+        let input = "id=\"a3fWa\"; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Max-Age=60; Domain=[::1]; Path=/docs; Secure; SameSite=Lax; Priority=High; Foo=bar";
+
+        let set_cookie = parse_set_cookie(input).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&set_cookie).unwrap(),
+            json!({
+                "pair": { "name": "id", "value": "a3fWa", "quoted": true },
+                "secure": true,
+                "http_only": false,
+                "max_age": 60,
+                "domain": "[::1]",
+                "expires": "2015-10-21T07:28:00Z",
+                "path": "/docs",
+                "same_site": "Lax",
+                "partitioned": false,
+                "priority": "High",
+                "extensions": ["Foo=bar"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_set_cookie_json_round_trip() {
+        let inputs = [
+            "id=a3fWa",
+            "id=a3fWa; Domain=.Example.com; SameSite=Bogus; Max-Age=-1; Partitioned",
+            "id=a3fWa; Max-Age=99999999999999999999999; Domain=127.0.0.1",
+            "id=a3fWa; Max-Age=-99999999999999999999999",
+        ];
+
+        for input in inputs {
+            let set_cookie = parse_set_cookie(input).unwrap();
+            let json = serde_json::to_string(&set_cookie).unwrap();
+
+            match serde_json::from_str::<SetCookie>(&json) {
+                Ok(result) => assert_eq!(result, set_cookie, "input: {:?}", input),
+                Err(_) => assert!(false),
+            }
+        }
+
+        assert_eq!(
+            parse_set_cookie(inputs[2]).unwrap().max_age,
+            Some(Duration::MAX)
+        );
+    }
+
+    #[test]
+    fn test_invalid_json_values() {
+        let json = json!({
+            "pair": { "name": "id", "value": "a3fWa", "quoted": false },
+            "secure": false,
+            "http_only": false,
+            "max_age": null,
+            "domain": null,
+            "expires": null,
+            "path": null,
+            "same_site": null,
+            "partitioned": false,
+            "priority": "Urgent",
+            "extensions": [],
+        });
+
+        assert!(serde_json::from_value::<SetCookie>(json).is_err());
+    }
+
+    #[test]
+    fn test_deserialized_values_are_checked() {
+        let set_cookie = |field: &str, value: serde_json::Value| {
+            let mut json =
+                serde_json::to_value(parse_set_cookie("id=a3fWa; Path=/; Foo=bar").unwrap())
+                    .unwrap();
+            json[field] = value;
+
+            serde_json::from_value::<SetCookie>(json)
+        };
+
+        assert!(set_cookie(
+            "pair",
+            json!({ "name": "a;b", "value": "1", "quoted": false })
+        )
+        .is_err());
+        assert!(set_cookie(
+            "pair",
+            json!({ "name": "a=b", "value": "1", "quoted": false })
+        )
+        .is_err());
+        assert!(set_cookie(
+            "pair",
+            json!({ "name": "a", "value": "1\r\nX: 1", "quoted": false })
+        )
+        .is_err());
+        assert!(set_cookie("path", json!("/\r\nX-Evil: 1")).is_err());
+        assert!(set_cookie("same_site", json!("x;y")).is_err());
+        assert!(set_cookie("extensions", json!(["Secure"])).is_err());
+        assert!(set_cookie("extensions", json!(["Max-Age=60"])).is_err());
+        assert!(set_cookie("extensions", json!(["Foo=bar; Secure"])).is_err());
+
+        let lenient = parse_set_cookie_lenient("id = a b ; SameSite ; Path=/a\tb").unwrap();
+        let json = serde_json::to_string(&lenient).unwrap();
+
+        match serde_json::from_str::<SetCookie>(&json) {
+            Ok(result) => assert_eq!(result, lenient),
+            Err(_) => assert!(false),
+        }
+
+        let header = json!({
+            "pairs": [
+                { "name": "a", "value": "1", "quoted": false },
+                { "name": "a", "value": "2", "quoted": false },
+            ],
+            "policy": "Error",
+        });
+
+        assert!(serde_json::from_value::<CookieHeader>(header).is_err());
+    }
+
+    #[test]
+    fn test_borrowed_and_other_types() {
+        let input = "id=a3fWa; Path=/docs; Foo=bar";
+        let json = serde_json::to_string(&parse_set_cookie_borrowed(input).unwrap()).unwrap();
+
+        match serde_json::from_str::<SetCookieRef>(&json) {
+            Ok(result) => assert_eq!(result, parse_set_cookie_borrowed(input).unwrap()),
+            Err(_) => assert!(false),
+        }
+
+        let header = CookieHeader::parse("a=1; b=2").unwrap();
+        let json = serde_json::to_string(&header).unwrap();
+
+        assert_eq!(serde_json::from_str::<CookieHeader>(&json).unwrap(), header);
+        assert_eq!(
+            serde_json::to_value(CookiePair {
+                name: String::from("a"),
+                value: String::from("1"),
+                quoted: false,
+            })
+            .unwrap(),
+            json!({ "name": "a", "value": "1", "quoted": false })
+        );
+
+        let recovered = parse_set_cookie_recovering("id=a3fWa;Secure");
+
+        assert_eq!(
+            serde_json::to_value(&recovered.problems).unwrap()[0]["InvalidAttribute"]["offset"],
+            json!(9)
+        );
+    }
+}
@@ -0,0 +1,76 @@
+#![allow(clippy::assertions_on_constants)]
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use cookie_parser::{
+        parse_cookie_string, parse_set_cookie, CookieDomain, CookieHeader, CookiePair, Priority,
+        SameSite, SetCookie, ToHeaderString,
+    };
+
+    #[test]
+    fn test_set_cookie_to_string_canonical_order() {
+        let set_cookie = SetCookie {
+            pair: CookiePair {
+                name: String::from("id"),
+                value: String::from("a3fWa"),
+                quoted: true,
+            },
+            domain: Some(CookieDomain::Host(String::from("example.com"))),
+            max_age: Some(Duration::seconds(60)),
+            path: Some(String::from("/docs")),
+            expires: Some(Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap()),
+            same_site: Some(SameSite::Lax),
+            partitioned: true,
+            priority: Some(Priority::High),
+            http_only: true,
+            secure: true,
+            extensions: vec![String::from("Foo=bar")],
+        };
+
+        assert_eq!(
+            set_cookie.to_string(),
+            "id=\"a3fWa\"; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Max-Age=60; Domain=example.com; Path=/docs; Secure; HttpOnly; SameSite=Lax; Partitioned; Priority=High; Foo=bar"
+        );
+        assert_eq!(set_cookie.to_header_string(), set_cookie.to_string());
+    }
+
+    #[test]
+    fn test_cookie_pairs_to_header_string() {
+        let input = "a=1; b=\"2\"; c=abc==";
+
+        match parse_cookie_string(input) {
+            Ok(result) => {
+                assert_eq!(result.to_header_string(), input);
+                assert_eq!(result[1].to_string(), "b=\"2\"");
+                assert_eq!(CookieHeader::parse(input).unwrap().to_string(), input);
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_set_cookie_round_trip() {
+        let inputs = [
+            "id=a3fWa",
+            "id=\"a3fWa\"; HttpOnly; Foo=bar; Secure; Baz",
+            "id=a3fWa; expires=Wednesday, 21-Oct-15 07:28:00 GMT; max-age = -1",
+            "id=a3fWa; Max-Age=0; Path=/a b; SameSite=Bogus ",
+            "id=a3fWa; Max-Age=99999999999999999999999",
+            "id=a3fWa; Max-Age=-99999999999999999999999",
+            "id=a3fWa; Domain=.Bücher.Example; Priority=low; Partitioned",
+            "id=a3fWa; Domain=127.0.0.1; Path=/",
+            "id=a3fWa; Domain=[::1]; samesite=none",
+            "id=a3fWa; Expires=Wed Oct 21 07:28:00 1601; Max-Age=",
+        ];
+
+        for input in inputs {
+            let set_cookie = parse_set_cookie(input).unwrap();
+
+            match parse_set_cookie(&set_cookie.to_string()) {
+                Ok(result) => assert_eq!(result, set_cookie, "input: {:?}", input),
+                Err(_) => assert!(false),
+            }
+        }
+    }
+}